@@ -0,0 +1,123 @@
+//! APDU commands that send a test vector's `blob` to the Ledger app for signing.
+//!
+//! The blob is split in the same way Zondax's JS libraries do it: the first APDU carries
+//! only the BIP-32 derivation path, the following ones carry consecutive chunks of the blob.
+//! P1 of every APDU tells the app whether it's the first, a middle or the last one.
+
+/// Class of all the Casper Ledger app's instructions.
+const CLA: u8 = 0x11;
+
+/// Maximum number of data bytes in a single APDU.
+/// The limit of the APDU format is 255, Zondax's libraries send at most 250.
+const CHUNK_SIZE: usize = 250;
+
+// Payload types, sent as P1.
+const P1_INIT: u8 = 0x00;
+const P1_ADD: u8 = 0x01;
+const P1_LAST: u8 = 0x02;
+
+// Not used by the signing instructions.
+const P2: u8 = 0x00;
+
+const HARDENED: u32 = 0x8000_0000;
+
+/// Derivation path of the first Casper account: m/44'/506'/0'/0/0.
+const DEFAULT_PATH: [u32; 5] = [44 | HARDENED, 506 | HARDENED, HARDENED, 0, 0];
+
+/// Instructions the blob can be sent with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Instruction {
+    /// Sign a `Deploy`.
+    SignDeploy,
+    /// Sign a `CasperMessage`.
+    SignMessage,
+}
+
+impl Instruction {
+    fn ins(&self) -> u8 {
+        match self {
+            Instruction::SignDeploy => 0x02,
+            Instruction::SignMessage => 0x03,
+        }
+    }
+}
+
+// Each of the path's components is serialized as a little-endian u32.
+fn serialize_path(path: &[u32]) -> Vec<u8> {
+    path.iter()
+        .flat_map(|component| component.to_le_bytes())
+        .collect()
+}
+
+/// Returns the APDUs that send `blob` with the `instruction`, for the default derivation path.
+pub(crate) fn apdus(instruction: Instruction, blob: &[u8]) -> Vec<Vec<u8>> {
+    let mut chunks = vec![serialize_path(&DEFAULT_PATH)];
+    chunks.extend(blob.chunks(CHUNK_SIZE).map(<[u8]>::to_vec));
+
+    let last = chunks.len() - 1;
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let p1 = if idx == last {
+                P1_LAST
+            } else if idx == 0 {
+                P1_INIT
+            } else {
+                P1_ADD
+            };
+            let mut apdu = vec![CLA, instruction.ins(), p1, P2, chunk.len() as u8];
+            apdu.extend(chunk);
+            apdu
+        })
+        .collect()
+}
+
+/// Returns hex-encoded APDUs that send `blob` with the `instruction`.
+pub(crate) fn apdus_hex(instruction: Instruction, blob: &[u8]) -> Vec<String> {
+    apdus(instruction, blob).iter().map(hex::encode).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apdus, Instruction, CHUNK_SIZE, P1_ADD, P1_INIT, P1_LAST};
+
+    #[test]
+    fn first_apdu_carries_path() {
+        let apdus = apdus(Instruction::SignDeploy, &[0xaa]);
+        assert_eq!(
+            hex::encode(&apdus[0]),
+            "1102000014\
+             2c000080fa010080000000800000000000000000"
+        );
+        assert_eq!(hex::encode(&apdus[1]), "1102020001aa");
+    }
+
+    #[test]
+    fn blob_is_chunked() {
+        let cases = [
+            (1, 2),
+            (CHUNK_SIZE, 2),
+            (CHUNK_SIZE + 1, 3),
+            (3 * CHUNK_SIZE, 4),
+        ];
+        for (blob_len, apdus_count) in cases {
+            let blob: Vec<u8> = (0..blob_len).map(|byte| byte as u8).collect();
+            let apdus = apdus(Instruction::SignMessage, &blob);
+
+            assert_eq!(apdus.len(), apdus_count);
+            let p1s: Vec<u8> = apdus.iter().map(|apdu| apdu[2]).collect();
+            assert_eq!(p1s.first(), Some(&P1_INIT));
+            assert_eq!(p1s.last(), Some(&P1_LAST));
+            assert!(p1s[1..p1s.len() - 1].iter().all(|p1| *p1 == P1_ADD));
+
+            let mut sent = vec![];
+            for apdu in &apdus[1..] {
+                assert_eq!(apdu[1], 0x03);
+                assert_eq!(apdu[4] as usize, apdu.len() - 5);
+                sent.extend(&apdu[5..]);
+            }
+            assert_eq!(sent, blob);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 use casper_types::blake2b;
 
 pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
@@ -53,15 +55,114 @@ fn encode_iter<'a, T: 'a + AsRef<[u8]>>(input: &'a T) -> impl Iterator<Item = ch
     })
 }
 
+/// Reasons why a string is not a valid checksummed hex.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Hex string has an odd number of characters.
+    InvalidLength(usize),
+    /// Character at `index` is not a hex digit.
+    InvalidCharacter { index: usize, character: char },
+    /// Case of the character at `index` does not match the checksum.
+    InvalidChecksum { index: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength(length) => write!(f, "odd number of hex characters: {}", length),
+            Error::InvalidCharacter { index, character } => {
+                write!(f, "invalid hex character {:?} at {}", character, index)
+            }
+            Error::InvalidChecksum { index } => {
+                write!(f, "character at {} does not match the checksum", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Decodes the checksummed hex string, verifying its checksum.
+///
+/// All-lowercase input is accepted without verifying the checksum, as it's how the inputs
+/// longer than [`SMALL_BYTES_COUNT`] are encoded and how non-checksummed hex usually looks like.
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, Error> {
+    let input = input.as_ref();
+    if input.len() % 2 != 0 {
+        return Err(Error::InvalidLength(input.len()));
+    }
+    if let Some(index) = input.iter().position(|c| !c.is_ascii_hexdigit()) {
+        return Err(Error::InvalidCharacter {
+            index,
+            character: input[index] as char,
+        });
+    }
+    let bytes = base16::decode(input).expect("input should be a valid hex");
+
+    if input.iter().all(|c| !c.is_ascii_uppercase()) {
+        return Ok(bytes);
+    }
+    let expected = encode(&bytes);
+    match expected
+        .bytes()
+        .zip(input.iter())
+        .position(|(expected, actual)| expected != *actual)
+    {
+        Some(index) => Err(Error::InvalidChecksum { index }),
+        None => Ok(bytes),
+    }
+}
+
+/// Verifies that the input is a valid checksummed hex string.
+pub fn verify<T: AsRef<[u8]>>(input: T) -> Result<(), Error> {
+    decode(input).map(|_| ())
+}
+
 #[cfg(test)]
 mod tests {
-    use casper_hashing::Digest;
+    use super::{decode, encode, verify, Error, SMALL_BYTES_COUNT};
+
+    #[test]
+    fn decode_encoded() {
+        for length in [0, 1, 32, 33, 34, SMALL_BYTES_COUNT, SMALL_BYTES_COUNT + 1] {
+            let bytes: Vec<u8> = (0..length).map(|i| (i * 37) as u8).collect();
+            assert_eq!(Ok(bytes.clone()), decode(encode(&bytes)));
+        }
+    }
+
+    #[test]
+    fn lowercase_is_unchecked() {
+        let bytes = [0xabu8; 32];
+        assert!(encode(bytes).chars().any(|c| c.is_ascii_uppercase()));
+        assert_eq!(Ok(bytes.to_vec()), decode(base16::encode_lower(&bytes)));
+    }
+
+    #[test]
+    fn wrong_case_is_rejected() {
+        let bytes = [0xabu8; 32];
+        let encoded = encode(bytes);
+        // Uppercasing a letter keeps the input mixed-case, so the checksum is still verified.
+        let index = encoded.find(|c: char| c.is_ascii_lowercase()).unwrap();
+        let mut tampered = encoded.clone();
+        tampered.replace_range(index..=index, &encoded[index..=index].to_uppercase());
+        assert_eq!(Err(Error::InvalidChecksum { index }), verify(tampered));
+
+        let long_bytes = [0xabu8; SMALL_BYTES_COUNT + 1];
+        assert_eq!(
+            Err(Error::InvalidChecksum { index: 0 }),
+            verify(base16::encode_upper(&long_bytes))
+        );
+    }
 
     #[test]
-    fn tmp() {
-        let bytes =
-            Digest::from_hex(b"4acfcf6c684c58caf6b3296e3a97c4a04afaf77bb875ca9a40a45db254e94a75")
-                .unwrap();
-        println!("{:?}", &bytes.into_vec())
+    fn invalid_hex_is_rejected() {
+        assert_eq!(Err(Error::InvalidLength(3)), decode("abc"));
+        assert_eq!(
+            Err(Error::InvalidCharacter {
+                index: 1,
+                character: 'x'
+            }),
+            decode("0x")
+        );
     }
 }
@@ -0,0 +1,284 @@
+//! Command line interface of the test vector generator.
+
+use std::path::PathBuf;
+
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand, ValueEnum};
+
+use crate::{
+    ledger::DeviceProfile,
+    parser::{
+        ArgsConfig, ArgsMode, KnownChains, ParserConfig, MAX_LISTED_ARGS, MAX_LISTED_SIGNERS,
+    },
+    test_rng::TestRng,
+};
+
+#[derive(Parser)]
+#[command(about = "Generates test vectors for the Casper Ledger app.")]
+pub(crate) struct Cli {
+    #[command(subcommand)]
+    pub(crate) command: Command,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Generates test vectors.
+    Generate(GenerateArgs),
+    /// Renders a single test vector as Ledger pages.
+    Render(RenderArgs),
+    /// Checks that freshly generated test vectors match the ones in an existing file.
+    Verify(VerifyArgs),
+    /// Compares two test vector files and fails when any of the old test vectors was removed or changed.
+    Diff(DiffArgs),
+    /// Generates test vectors for the checksummed hex encoding.
+    ChecksumVectors(ChecksumVectorsArgs),
+}
+
+/// Sample families, in the order they are generated by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Family {
+    Undelegate,
+    Delegate,
+    NativeTransfer,
+    Redelegate,
+    Generic,
+    PageLimit,
+    Message,
+    Malformed,
+    AddBid,
+    WithdrawBid,
+    ActivateBid,
+    GenericArgs,
+    ChainName,
+    Amounts,
+    Tampered,
+    Wasm,
+    CustomPayment,
+    Cep18,
+    Cep78,
+    SelfTransfer,
+    Recipient,
+}
+
+/// Ledger devices test vectors can be generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Device {
+    NanoS,
+    NanoSPlus,
+    NanoX,
+    Stax,
+    Flex,
+}
+
+impl Device {
+    /// Returns display limits of the device.
+    pub(crate) fn profile(&self) -> DeviceProfile {
+        match self {
+            Device::NanoS => DeviceProfile::NANO_S,
+            Device::NanoSPlus => DeviceProfile::NANO_S_PLUS,
+            Device::NanoX => DeviceProfile::NANO_X,
+            Device::Stax => DeviceProfile::STAX,
+            Device::Flex => DeviceProfile::FLEX,
+        }
+    }
+
+    /// Returns the name of the device, as accepted on the command line.
+    pub(crate) fn name(&self) -> String {
+        self.to_possible_value()
+            .expect("no skipped variants")
+            .get_name()
+            .to_string()
+    }
+}
+
+/// Ways of presenting the arguments of contract calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ArgsDisplay {
+    /// Hash of all the arguments.
+    Hash,
+    /// Hash of all the arguments, and the list of arguments in the expert mode.
+    ExpertList,
+    /// List of arguments in both modes.
+    FullList,
+}
+
+impl ArgsDisplay {
+    pub(crate) fn mode(&self) -> ArgsMode {
+        match self {
+            ArgsDisplay::Hash => ArgsMode::Hash,
+            ArgsDisplay::ExpertList => ArgsMode::ExpertList,
+            ArgsDisplay::FullList => ArgsMode::FullList,
+        }
+    }
+
+    /// Returns the name of the mode, as accepted on the command line.
+    pub(crate) fn name(&self) -> String {
+        self.to_possible_value()
+            .expect("no skipped variants")
+            .get_name()
+            .to_string()
+    }
+}
+
+/// Options that influence the contents of the generated test vectors.
+#[derive(Args)]
+pub(crate) struct GeneratorOpts {
+    /// Comma-separated list of sample families to generate. All of them when not set.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    families: Vec<Family>,
+    /// Hex-encoded, 16 bytes long seed for the PRNG.
+    /// Defaults to `CL_TEST_SEED` env variable or a random one if that's not set either.
+    #[arg(long, value_parser = parse_seed)]
+    seed: Option<[u8; 16]>,
+    /// Number of pages above which transaction is considered too complex for the regular mode.
+    #[arg(long, default_value_t = 15)]
+    pub(crate) page_limit: u8,
+    /// How the arguments of contract calls are presented.
+    #[arg(long, value_enum, default_value_t = ArgsDisplay::Hash)]
+    args_mode: ArgsDisplay,
+    /// Maximum number of arguments that are listed, at most 100. Above that only their hash is presented.
+    #[arg(
+        long,
+        default_value_t = 4,
+        value_parser = RangedU64ValueParser::<usize>::new().range(..=MAX_LISTED_ARGS)
+    )]
+    max_args: usize,
+    /// Maximum length of a listed argument's value. Above that only the hash of arguments is presented.
+    #[arg(long, default_value_t = 64)]
+    max_arg_len: usize,
+    /// Comma-separated list of names of the main network chains.
+    #[arg(long, value_delimiter = ',', default_value = "casper")]
+    mainnet_chains: Vec<String>,
+    /// Comma-separated list of names of the test network chains.
+    #[arg(long, value_delimiter = ',', default_value = "casper-test")]
+    testnet_chains: Vec<String>,
+    /// Maximum number of approvals' signers listed in the expert mode, at most 9999.
+    #[arg(
+        long,
+        default_value_t = 5,
+        value_parser = RangedU64ValueParser::<usize>::new().range(..=MAX_LISTED_SIGNERS)
+    )]
+    max_signers: usize,
+    /// Adds the APDUs that send each test vector's blob to Ledger for signing.
+    #[arg(long)]
+    pub(crate) apdus: bool,
+}
+
+impl GeneratorOpts {
+    /// Returns requested sample families.
+    pub(crate) fn families(&self) -> Vec<Family> {
+        if self.families.is_empty() {
+            return Family::value_variants().to_vec();
+        }
+        self.families.clone()
+    }
+
+    /// Returns configuration of the transaction presentation.
+    pub(crate) fn parser_config(&self) -> ParserConfig {
+        ParserConfig::new(
+            self.args_config(),
+            KnownChains::new(self.mainnet_chains.clone(), self.testnet_chains.clone()),
+            self.max_signers,
+        )
+    }
+
+    /// Returns configuration of the arguments' presentation, in the requested mode.
+    pub(crate) fn args_config(&self) -> ArgsConfig {
+        self.args_config_for(self.args_mode)
+    }
+
+    /// Returns configuration of the arguments' presentation, in the given mode.
+    pub(crate) fn args_config_for(&self, args_mode: ArgsDisplay) -> ArgsConfig {
+        ArgsConfig::new(args_mode.mode(), self.max_args, self.max_arg_len)
+    }
+
+    /// Returns PRNG seeded with the requested seed.
+    pub(crate) fn rng(&self) -> TestRng {
+        match self.seed {
+            Some(seed) => TestRng::from_seed(seed),
+            None => TestRng::new(),
+        }
+    }
+}
+
+// Parses the hex-encoded `--seed`.
+fn parse_seed(seed_as_hex: &str) -> Result<[u8; 16], String> {
+    let mut seed = [0u8; 16];
+    hex::decode_to_slice(seed_as_hex, &mut seed)
+        .map_err(|error| format!("can't parse '{}' as a TestRng seed: {}", seed_as_hex, error))?;
+    Ok(seed)
+}
+
+#[derive(Args)]
+pub(crate) struct GenerateArgs {
+    #[command(flatten)]
+    pub(crate) opts: GeneratorOpts,
+    /// File to write test vectors to. Printed to stdout when not set.
+    /// When generating for more than one device, the device name is appended to the file name.
+    #[arg(long)]
+    pub(crate) output: Option<PathBuf>,
+    /// Comma-separated list of devices to generate test vectors for.
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "nano-s")]
+    pub(crate) devices: Vec<Device>,
+}
+
+/// Encoding of the `Deploy` passed to the `render` command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum DeployFormat {
+    /// JSON, as returned by casper-node and casper-client.
+    Json,
+    /// Hex-encoded `ToBytes` representation.
+    Hex,
+}
+
+#[derive(Args)]
+pub(crate) struct RenderArgs {
+    #[command(flatten)]
+    pub(crate) opts: GeneratorOpts,
+    /// Index of the generated test vector to render.
+    #[arg(long, required_unless_present = "deploy", conflicts_with = "deploy")]
+    pub(crate) index: Option<usize>,
+    /// File with the `Deploy` to render instead of one of the generated test vectors.
+    #[arg(long)]
+    pub(crate) deploy: Option<PathBuf>,
+    /// Encoding of the `--deploy` file.
+    #[arg(long, value_enum, default_value_t = DeployFormat::Json)]
+    pub(crate) format: DeployFormat,
+    /// Device to render the pages for.
+    #[arg(long, value_enum, default_value_t = Device::NanoS)]
+    pub(crate) device: Device,
+}
+
+#[derive(Args)]
+pub(crate) struct VerifyArgs {
+    #[command(flatten)]
+    pub(crate) opts: GeneratorOpts,
+    /// File with test vectors to compare against.
+    #[arg(long, default_value = "manual.json")]
+    pub(crate) input: PathBuf,
+    /// Device the test vectors were generated for.
+    #[arg(long, value_enum, default_value_t = Device::NanoS)]
+    pub(crate) device: Device,
+}
+
+#[derive(Args)]
+pub(crate) struct DiffArgs {
+    /// File with the old (golden) test vectors.
+    #[arg(long, default_value = "old_manual.json")]
+    pub(crate) old: PathBuf,
+    /// File with the new test vectors.
+    #[arg(long, default_value = "manual.json")]
+    pub(crate) new: PathBuf,
+    /// Comma-separated list of names of the test vectors that are allowed to be removed or changed.
+    #[arg(long, value_delimiter = ',')]
+    pub(crate) allow: Vec<String>,
+    /// Allows any of the old test vectors to be removed or changed.
+    #[arg(long)]
+    pub(crate) allow_all: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct ChecksumVectorsArgs {
+    /// File to write test vectors to. Printed to stdout when not set.
+    #[arg(long)]
+    pub(crate) output: Option<PathBuf>,
+}
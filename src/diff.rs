@@ -0,0 +1,225 @@
+//! Comparison of two sets of test vectors.
+//!
+//! Test vectors are matched by their `name` and `blob`, so that the comparison is not affected
+//! by vectors being added or removed (which shifts the indexes of the following ones).
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::ledger::ZondaxRepr;
+
+/// Test vector that is present in both sets but is represented differently.
+pub(crate) struct ChangedVector<'a> {
+    old: &'a ZondaxRepr,
+    new: &'a ZondaxRepr,
+}
+
+/// Differences between the old and the new set of test vectors.
+pub(crate) struct VectorsDiff<'a> {
+    pub(crate) added: Vec<&'a ZondaxRepr>,
+    pub(crate) removed: Vec<&'a ZondaxRepr>,
+    pub(crate) changed: Vec<ChangedVector<'a>>,
+}
+
+impl<'a> VectorsDiff<'a> {
+    pub(crate) fn new(old: &'a [ZondaxRepr], new: &'a [ZondaxRepr]) -> Self {
+        // The same sample may be generated more than once, so every key maps to all of its vectors.
+        let mut new_by_key: BTreeMap<(&str, &str), Vec<&ZondaxRepr>> = BTreeMap::new();
+        for vector in new.iter().rev() {
+            new_by_key
+                .entry((vector.name(), vector.blob()))
+                .or_default()
+                .push(vector);
+        }
+
+        let mut removed = vec![];
+        let mut changed = vec![];
+        for old_vector in old {
+            match new_by_key
+                .get_mut(&(old_vector.name(), old_vector.blob()))
+                .and_then(Vec::pop)
+            {
+                Some(new_vector) if !is_same(old_vector, new_vector) => {
+                    changed.push(ChangedVector {
+                        old: old_vector,
+                        new: new_vector,
+                    })
+                }
+                Some(_) => {}
+                None => removed.push(old_vector),
+            }
+        }
+
+        let mut added: Vec<&ZondaxRepr> = new_by_key.into_values().flatten().collect();
+        added.sort_by_key(|vector| vector.index());
+
+        VectorsDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+}
+
+impl<'a> ChangedVector<'a> {
+    /// Old version of the test vector.
+    pub(crate) fn old(&self) -> &'a ZondaxRepr {
+        self.old
+    }
+}
+
+// Index is not compared since it changes whenever a vector is added or removed before this one.
+fn is_same(old: &ZondaxRepr, new: &ZondaxRepr) -> bool {
+    old.valid_regular() == new.valid_regular()
+        && old.valid_expert() == new.valid_expert()
+        && old.testnet() == new.testnet()
+        && old.invalid_reason() == new.invalid_reason()
+        && old.output() == new.output()
+        && old.output_expert() == new.output_expert()
+}
+
+/// Returns the pages that have to be removed from `old` (`-` prefixed) and added (`+` prefixed)
+/// to get `new`, in the order of their occurrence.
+pub(crate) fn pages_diff(old: &[String], new: &[String]) -> Vec<String> {
+    // Length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|page| format!("- {}", page)));
+    diff.extend(new[j..].iter().map(|page| format!("+ {}", page)));
+    diff
+}
+
+impl<'a> Display for ChangedVector<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "changed: #{} -> #{} {}",
+            self.old.index(),
+            self.new.index(),
+            self.old.name()
+        )?;
+        let flags = [
+            (
+                "valid_regular",
+                self.old.valid_regular(),
+                self.new.valid_regular(),
+            ),
+            (
+                "valid_expert",
+                self.old.valid_expert(),
+                self.new.valid_expert(),
+            ),
+            ("testnet", self.old.testnet(), self.new.testnet()),
+        ];
+        for (flag, old, new) in flags {
+            if old != new {
+                writeln!(f, "  {}: {} -> {}", flag, old, new)?;
+            }
+        }
+        if self.old.invalid_reason() != self.new.invalid_reason() {
+            writeln!(
+                f,
+                "  invalid_reason: {:?} -> {:?}",
+                self.old.invalid_reason(),
+                self.new.invalid_reason()
+            )?;
+        }
+        let pages = [
+            ("output", self.old.output(), self.new.output()),
+            (
+                "output_expert",
+                self.old.output_expert(),
+                self.new.output_expert(),
+            ),
+        ];
+        for (label, old, new) in pages {
+            let diff = pages_diff(old, new);
+            if !diff.is_empty() {
+                writeln!(f, "  {}:", label)?;
+                for line in diff {
+                    writeln!(f, "    {}", line)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Display for VectorsDiff<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for vector in &self.removed {
+            writeln!(f, "removed: #{} {}", vector.index(), vector.name())?;
+        }
+        for vector in &self.added {
+            writeln!(f, "added: #{} {}", vector.index(), vector.name())?;
+        }
+        for vector in &self.changed {
+            write!(f, "{}", vector)?;
+        }
+        write!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pages_diff;
+
+    fn pages(pages: &[&str]) -> Vec<String> {
+        pages.iter().map(|page| page.to_string()).collect()
+    }
+
+    #[test]
+    fn same_pages_have_no_diff() {
+        let old = pages(&["0 | Type : Delegate", "1 | Chain ID : casper"]);
+        assert!(pages_diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn changed_page_is_removed_and_added() {
+        let old = pages(&["0 | Type : Delegate", "1 | Fee : CSPR 1", "2 | Amount : 2"]);
+        let new = pages(&["0 | Type : Delegate", "1 | Fee : CSPR 2", "2 | Amount : 2"]);
+        assert_eq!(
+            pages_diff(&old, &new),
+            pages(&["- 1 | Fee : CSPR 1", "+ 1 | Fee : CSPR 2"])
+        );
+    }
+
+    #[test]
+    fn trailing_pages() {
+        let old = pages(&["0 | Type : Delegate"]);
+        let new = pages(&["0 | Type : Delegate", "1 | Chain ID : casper"]);
+        assert_eq!(pages_diff(&old, &new), pages(&["+ 1 | Chain ID : casper"]));
+        assert_eq!(pages_diff(&new, &old), pages(&["- 1 | Chain ID : casper"]));
+    }
+}
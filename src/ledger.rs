@@ -5,14 +5,58 @@ use casper_types::bytesrepr::ToBytes;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{message::CasperMessage, parser, sample::Sample};
+use crate::{
+    apdu::{self, Instruction},
+    message::CasperMessage,
+    parser::{self, ArgsConfig, ParseError, ParserConfig},
+    sample::Sample,
+    verification,
+};
+
+/// Display limits of a Ledger device model.
+///
+/// Zondax apps size their pages per target with `MAX_CHARS_PER_KEY_LINE` and
+/// `MAX_CHARS_PER_VALUE*_LINE` in ledger-zxlib (`include/view_internal.h`),
+/// on Stax and Flex on top of the NBGL layouts from Ledger's secure SDK (`lib_nbgl`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct DeviceProfile {
+    // Character limit for Ledger's "label" row.
+    name_char_count: usize,
+    // Character limits of Ledger's value rows, from the top one to the bottom one.
+    value_row_char_counts: &'static [usize],
+}
 
-// Character limit for Ledger's "label" row.
-const LEDGER_VIEW_NAME_CHAR_COUNT: usize = 11;
-// Character limit for Ledger's value top row.
-const LEDGER_VIEW_TOP_ROW_CHAR_COUNT: usize = 17;
-// Character limit for Ledger's value bottom row.
-const LEDGER_VIEW_BOTTOM_CHAR_COUNT: usize = 17;
+impl DeviceProfile {
+    /// Ledger Nano S: 11 characters for the label and two rows of 17 characters for the value.
+    pub(crate) const NANO_S: DeviceProfile = DeviceProfile {
+        name_char_count: 11,
+        value_row_char_counts: &[17, 17],
+    };
+
+    /// Ledger Nano S Plus: 20 characters for the label and four rows of 20 characters for the value,
+    /// as set for `TARGET_NANOS2` in ledger-zxlib's `view_internal.h`.
+    pub(crate) const NANO_S_PLUS: DeviceProfile = DeviceProfile {
+        name_char_count: 20,
+        value_row_char_counts: &[20, 20, 20, 20],
+    };
+
+    /// Ledger Nano X: same screen as Nano S Plus, ledger-zxlib shares the `TARGET_NANOX` limits with it.
+    pub(crate) const NANO_X: DeviceProfile = DeviceProfile::NANO_S_PLUS;
+
+    /// Ledger Stax: 30 characters for the label and eight rows of 26 characters for the value,
+    /// as set for `TARGET_STAX` in ledger-zxlib's `view_internal.h` to fit the NBGL review pages.
+    pub(crate) const STAX: DeviceProfile = DeviceProfile {
+        name_char_count: 30,
+        value_row_char_counts: &[26, 26, 26, 26, 26, 26, 26, 26],
+    };
+
+    /// Ledger Flex: 30 characters for the label and six rows of 30 characters for the value,
+    /// as set for `TARGET_FLEX` in ledger-zxlib's `view_internal.h` to fit the NBGL review pages.
+    pub(crate) const FLEX: DeviceProfile = DeviceProfile {
+        name_char_count: 30,
+        value_row_char_counts: &[30, 30, 30, 30, 30, 30],
+    };
+}
 
 #[derive(Clone, Copy)]
 pub(crate) enum TxnPhase {
@@ -78,66 +122,90 @@ impl Element {
     pub(crate) fn as_expert(&mut self) {
         self.expert = true;
     }
+
+    /// Flips the "expert" bit to `false`.
+    pub(crate) fn as_regular(&mut self) {
+        self.expert = false;
+    }
 }
 
 #[derive(Clone)]
 #[allow(unused)]
 struct Ledger {
     ledger_elements: Vec<Element>,
+    // Labels of the elements making up the basic info.
+    basic_info_labels: &'static [&'static str],
 }
 
 impl Ledger {
-    fn from_deploy(deploy: Deploy) -> Self {
-        Ledger {
-            ledger_elements: parser::parse_deploy(deploy),
-        }
+    fn from_deploy(deploy: Deploy, parser_config: &ParserConfig) -> Result<Self, ParseError> {
+        Ok(Ledger {
+            ledger_elements: parser::parse_deploy(deploy, parser_config)?,
+            basic_info_labels: &BASIC_INFO_LABELS,
+        })
     }
 
-    fn from_message(casper_message: CasperMessage) -> Self {
-        Ledger {
-            ledger_elements: parser::parse_message(casper_message),
-        }
+    fn from_message(casper_message: CasperMessage) -> Result<Self, ParseError> {
+        Ok(Ledger {
+            ledger_elements: parser::parse_message(casper_message)?,
+            basic_info_labels: &MESSAGE_BASIC_INFO_LABELS,
+        })
     }
 
     pub(crate) fn into_ledger_elements(self) -> impl Iterator<Item = Element> {
         self.ledger_elements.into_iter()
     }
+
+    // Returns elements that are present in every transaction (or message), regardless of its type.
+    // They are displayed in the regular mode, even if they are expert-only otherwise.
+    fn basic_info(&self) -> Vec<Element> {
+        self.ledger_elements
+            .iter()
+            .filter(|element| self.basic_info_labels.contains(&element.name.as_str()))
+            .cloned()
+            .map(|mut element| {
+                element.as_regular();
+                element
+            })
+            .collect()
+    }
 }
 
 #[derive(Default, Clone)]
 struct LedgerValue {
-    top: String,
-    bottom: String,
+    rows: Vec<String>,
 }
 
 impl LedgerValue {
     // Adds a char to the ledger value.
     // Single value is limited by the number of chars that can be
-    // printed on one ledger view: 34 char total in two lines.
+    // printed on one ledger view, as defined by the device's value rows
+    // (for Nano S: 34 char total in two lines).
     // Function first tries to add a new char to the top row, if that is full
-    // then tries to add it to the bottom row.
+    // then tries to add it to the next row, and so on.
     // Returns whether adding char was successful.
-    fn add_char(&mut self, c: char) -> bool {
-        if self.top.chars().count() < LEDGER_VIEW_TOP_ROW_CHAR_COUNT {
-            self.top = format!("{}{}", self.top, c);
-            return true;
-        }
-        if self.bottom.chars().count() < LEDGER_VIEW_BOTTOM_CHAR_COUNT {
-            self.bottom = format!("{}{}", self.bottom, c);
-            return true;
+    fn add_char(&mut self, c: char, device: &DeviceProfile) -> bool {
+        for (row_idx, row_char_count) in device.value_row_char_counts.iter().enumerate() {
+            if self.rows.len() <= row_idx {
+                self.rows.push(String::new());
+            }
+            if self.rows[row_idx].chars().count() < *row_char_count {
+                self.rows[row_idx].push(c);
+                return true;
+            }
         }
         false
     }
 
-    // Concatenates both rows into single `String`.
+    // Concatenates all rows into single `String`.
     fn into_str(&self) -> String {
-        format!("{}{}", self.top, self.bottom)
+        self.rows.concat()
     }
 }
 
 impl std::fmt::Display for LedgerValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.top, self.bottom)
+        write!(f, "{}", self.rows.concat())
     }
 }
 
@@ -160,33 +228,34 @@ struct LedgerPageView {
 impl LedgerPageView {
     /// Parses an `Element` object (which represents a single piece of a transaction) into a Ledger representation -
     /// including chopping up the string representation of the `Element` so that they can fit on a single Ledger screen.
-    fn from_element(element: Element) -> Self {
-        if element.name.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT {
-            panic!(
-                "Name tag can only be {} elements. Tag: {}",
-                LEDGER_VIEW_NAME_CHAR_COUNT, element.name
-            )
+    /// Fails when the element's label doesn't fit into the device's label row.
+    fn from_element(element: Element, device: &DeviceProfile) -> Result<Self, ParseError> {
+        if element.name.chars().count() > device.name_char_count {
+            return Err(ParseError::LabelTooLong {
+                label: element.name,
+                max_len: device.name_char_count,
+            });
         }
         let mut values = vec![];
         let mut curr_value = LedgerValue::default();
         for c in element.value.chars() {
-            let added = curr_value.add_char(c);
+            let added = curr_value.add_char(c, device);
             if !added {
                 // Single ledger page can't contain more characters.
                 values.push(curr_value.clone());
                 // Create new Ledger page for that element.
                 curr_value = LedgerValue::default();
-                assert!(curr_value.add_char(c));
+                assert!(curr_value.add_char(c, device));
             }
         }
         // Add the last view to the collection.
         values.push(curr_value);
 
-        LedgerPageView {
+        Ok(LedgerPageView {
             name: element.name.clone(),
             expert: element.expert,
             values,
-        }
+        })
     }
 
     /// Turn the current element into printable Ledger views.
@@ -218,12 +287,24 @@ struct LedgerView {
 }
 
 impl LedgerView {
-    fn from_ledger(ledger: Ledger) -> Self {
-        let pages = ledger
-            .into_ledger_elements()
-            .map(LedgerPageView::from_element)
-            .collect();
-        LedgerView { pages }
+    fn from_ledger(ledger: Ledger, device: &DeviceProfile) -> Result<Self, ParseError> {
+        Self::from_elements(ledger.into_ledger_elements(), device)
+    }
+
+    fn from_elements<I: IntoIterator<Item = Element>>(
+        elements: I,
+        device: &DeviceProfile,
+    ) -> Result<Self, ParseError> {
+        let pages = elements
+            .into_iter()
+            .map(|element| LedgerPageView::from_element(element, device))
+            .collect::<Result<_, _>>()?;
+        Ok(LedgerView { pages })
+    }
+
+    // Number of Ledger pages user has to click through in the given mode.
+    fn page_count(&self, expert: bool) -> usize {
+        self.to_string(expert).len()
     }
 
     // Builds a vector of strings that follows the pattern:
@@ -252,66 +333,227 @@ impl LedgerView {
     }
 }
 
-#[derive(Clone)]
-#[allow(unused)]
+// Labels of the elements that are present in every transaction, regardless of its type.
+const BASIC_INFO_LABELS: [&str; 5] = ["Txn hash", "Type", "Chain ID", "Account", "Fee"];
 
+// Labels of the elements that are present in every message.
+// The hash is expert-only when the message's text is displayed, but it's the only thing left
+// to review when the text doesn't fit within the page limit.
+const MESSAGE_BASIC_INFO_LABELS: [&str; 1] = ["Msg hash"];
+
+// Message displayed in regular mode when the transaction does not fit within the page limit.
+const COMPLEXITY_NOTICE: &str = "Switch to expert mode";
+
+/// Configuration of the policy applied to transactions that span too many Ledger pages.
+///
+/// A transaction is considered oversized when its _regular_ mode representation
+/// is longer than `page_limit` pages. For such transactions the `on_regular` and `on_expert`
+/// callbacks replace the default representations.
+///
+/// `parser` decides how the transaction itself is presented.
+#[derive(Clone)]
 pub(crate) struct LimitedLedgerConfig {
     page_limit: u8,
-    on_regular: Rc<dyn Fn(&Ledger) -> Vec<String>>,
-    on_expert: Rc<dyn Fn(&Ledger) -> Vec<String>>,
+    device: DeviceProfile,
+    parser: ParserConfig,
+    on_regular: Rc<dyn Fn(&Ledger, &DeviceProfile) -> Result<Vec<String>, ParseError>>,
+    on_expert: Rc<dyn Fn(&Ledger, &DeviceProfile) -> Result<Vec<String>, ParseError>>,
 }
 
 impl LimitedLedgerConfig {
-    pub(crate) fn new(page_limit: u8) -> Self {
+    pub(crate) fn new(page_limit: u8, device: DeviceProfile, parser: ParserConfig) -> Self {
         Self {
             page_limit,
+            device,
+            parser,
             on_regular: Rc::new(Self::deploy_complexity_notice),
-            on_expert: Rc::new(Self::deploy_basic_info),
+            on_expert: Rc::new(Self::deploy_page_count_notice),
         }
     }
 
-    fn deploy_complexity_notice(_ledger: &Ledger) -> Vec<String> {
-        todo!()
+    /// Returns the same configuration, with contract arguments presented according to `args`.
+    pub(crate) fn with_args(&self, args: ArgsConfig) -> Self {
+        let mut config = self.clone();
+        config.parser.args = args;
+        config
     }
 
-    fn deploy_basic_info(_ledger: &Ledger) -> Vec<String> {
-        todo!()
+    /// Regular mode representation of an oversized transaction.
+    ///
+    /// Instead of making the user click through all of the pages, Ledger displays a notice
+    /// asking to switch to expert mode, followed by the basic info about the transaction
+    /// (so that it can still be cross-checked with the wallet).
+    fn deploy_complexity_notice(
+        ledger: &Ledger,
+        device: &DeviceProfile,
+    ) -> Result<Vec<String>, ParseError> {
+        let mut elements = vec![Element::regular("Notice", COMPLEXITY_NOTICE.to_string())];
+        elements.extend(ledger.basic_info());
+        Ok(LedgerView::from_elements(elements, device)?.to_string(false))
+    }
+
+    /// Expert mode representation of an oversized transaction.
+    ///
+    /// Expert mode is where the user is sent to review the transaction, so nothing is hidden.
+    /// Instead, Ledger starts with a page saying how many pages follow.
+    fn deploy_page_count_notice(
+        ledger: &Ledger,
+        device: &DeviceProfile,
+    ) -> Result<Vec<String>, ParseError> {
+        let page_count = LedgerView::from_ledger(ledger.clone(), device)?.page_count(true);
+        let mut elements = vec![Element::expert("Pages", format!("{}", page_count))];
+        elements.extend(ledger.clone().into_ledger_elements());
+        Ok(LedgerView::from_elements(elements, device)?.to_string(true))
     }
 }
 
 struct LimitedLedgerView<'a> {
-    _config: &'a LimitedLedgerConfig,
+    config: &'a LimitedLedgerConfig,
     ledger: Ledger,
 }
 
 impl<'a> LimitedLedgerView<'a> {
     fn new(config: &'a LimitedLedgerConfig, ledger: Ledger) -> Self {
-        Self {
-            _config: config,
-            ledger,
-        }
+        Self { config, ledger }
+    }
+
+    fn view(&self) -> Result<LedgerView, ParseError> {
+        LedgerView::from_ledger(self.ledger.clone(), &self.config.device)
     }
 
-    fn regular(&self) -> Vec<String> {
-        LedgerView::from_ledger(self.ledger.clone()).to_string(false)
+    // Whether regular mode representation of the transaction exceeds the page limit.
+    fn is_oversized(&self) -> Result<bool, ParseError> {
+        Ok(self.view()?.page_count(false) > self.config.page_limit as usize)
     }
 
-    fn expert(&self) -> Vec<String> {
-        LedgerView::from_ledger(self.ledger.clone()).to_string(true)
+    fn regular(&self) -> Result<Vec<String>, ParseError> {
+        if self.is_oversized()? {
+            return (self.config.on_regular)(&self.ledger, &self.config.device);
+        }
+        Ok(self.view()?.to_string(false))
+    }
+
+    fn expert(&self) -> Result<Vec<String>, ParseError> {
+        if self.is_oversized()? {
+            return (self.config.on_expert)(&self.ledger, &self.config.device);
+        }
+        Ok(self.view()?.to_string(true))
     }
 }
 
 /// Representation of a test vector that is structures in the way that Zondax's pipelines expect it.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub(super) struct ZondaxRepr {
     index: usize,
     name: String,
     valid_regular: bool,
     valid_expert: bool,
+    /// Why the sample could not be represented on Ledger. Present only for such samples.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    invalid_reason: Option<String>,
     testnet: bool,
     blob: String,
     output: Vec<String>,
     output_expert: Vec<String>,
+    /// Hex-encoded APDUs that send the `blob` to Ledger for signing. Present only when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    apdus: Option<Vec<String>>,
+}
+
+impl ZondaxRepr {
+    // Builds the test vector out of the sample's Ledger pages.
+    // Samples that could not be parsed are always invalid and have no pages.
+    fn new(
+        index: usize,
+        name: String,
+        valid: bool,
+        testnet: bool,
+        blob: String,
+        pages: Result<(Vec<String>, Vec<String>), ParseError>,
+    ) -> Self {
+        let (valid, invalid_reason, output, output_expert) = match pages {
+            Ok((output, output_expert)) => (valid, None, output, output_expert),
+            Err(error) => (false, Some(error.to_string()), vec![], vec![]),
+        };
+        ZondaxRepr {
+            index,
+            name,
+            valid_regular: valid,
+            valid_expert: valid,
+            invalid_reason,
+            testnet,
+            blob,
+            output,
+            output_expert,
+            apdus: None,
+        }
+    }
+
+    /// Marks the test vector as invalid for the `reason`, unless it's already invalid for another one.
+    /// Unlike with the parsing errors, pages are kept - they are what Ledger displays anyway.
+    fn rejected(mut self, reason: String) -> Self {
+        self.valid_regular = false;
+        self.valid_expert = false;
+        if self.invalid_reason.is_none() {
+            self.invalid_reason = Some(reason);
+        }
+        self
+    }
+
+    /// Adds the APDUs that send the `blob` with the `instruction`.
+    pub(super) fn with_apdus(mut self, instruction: Instruction) -> Self {
+        let blob = hex::decode(&self.blob).expect("blob should be a valid hex");
+        self.apdus = Some(apdu::apdus_hex(instruction, &blob));
+        self
+    }
+
+    pub(super) fn index(&self) -> usize {
+        self.index
+    }
+
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(super) fn blob(&self) -> &str {
+        &self.blob
+    }
+
+    pub(super) fn valid_regular(&self) -> bool {
+        self.valid_regular
+    }
+
+    pub(super) fn valid_expert(&self) -> bool {
+        self.valid_expert
+    }
+
+    pub(super) fn testnet(&self) -> bool {
+        self.testnet
+    }
+
+    pub(super) fn invalid_reason(&self) -> Option<&str> {
+        self.invalid_reason.as_deref()
+    }
+
+    /// Ledger pages displayed in the regular mode.
+    pub(super) fn output(&self) -> &[String] {
+        &self.output
+    }
+
+    /// Ledger pages displayed in the expert mode.
+    pub(super) fn output_expert(&self) -> &[String] {
+        &self.output_expert
+    }
+}
+
+/// Returns regular and expert mode Ledger pages of the `Deploy`.
+pub(super) fn deploy_pages(
+    deploy: Deploy,
+    config: &LimitedLedgerConfig,
+) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let ledger = Ledger::from_deploy(deploy, &config.parser)?;
+    let ledger_view = LimitedLedgerView::new(config, ledger);
+    Ok((ledger_view.regular()?, ledger_view.expert()?))
 }
 
 /// Maps `Deploy` structure to the expected JSON representation.
@@ -321,23 +563,47 @@ pub(super) fn deploy_to_json(
     config: &LimitedLedgerConfig,
 ) -> ZondaxRepr {
     let (name, deploy, valid) = sample_deploy.destructure();
+    // Ledger can't tell whether the hashes and approvals are correct, so the vector has to.
+    let verification = verification::verify_deploy(&deploy);
     let blob = hex::encode(&deploy.to_bytes().unwrap());
-    let ledger = Ledger::from_deploy(deploy);
-    let ledger_view = LimitedLedgerView::new(config, ledger);
-    let output = ledger_view.regular();
-    let output_expert = ledger_view.expert();
-    ZondaxRepr {
-        index,
-        name,
-        valid_regular: valid,
-        valid_expert: valid,
-        testnet: true,
-        blob,
-        output,
-        output_expert,
+    let testnet = is_testnet(&deploy, config);
+    let pages = deploy_pages(deploy, config);
+    let vector = ZondaxRepr::new(index, name, valid, testnet, blob, pages);
+    match verification {
+        Ok(()) => vector,
+        Err(error) => vector.rejected(error.to_string()),
     }
 }
 
+/// Returns number of Ledger pages the `Deploy` spans in the regular or expert mode
+/// on the device from `config`, before the page limit is applied.
+pub(super) fn deploy_page_count(
+    deploy: Deploy,
+    expert: bool,
+    config: &LimitedLedgerConfig,
+) -> Result<usize, ParseError> {
+    let ledger = Ledger::from_deploy(deploy, &config.parser)?;
+    Ok(LedgerView::from_ledger(ledger, &config.device)?.page_count(expert))
+}
+
+/// Returns regular and expert mode Ledger pages of the `CasperMessage`.
+pub(super) fn message_pages(
+    message: CasperMessage,
+    config: &LimitedLedgerConfig,
+) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let ledger = Ledger::from_message(message)?;
+    let ledger_view = LimitedLedgerView::new(config, ledger);
+    Ok((ledger_view.regular()?, ledger_view.expert()?))
+}
+
+/// Returns whether the `Deploy` is aimed at a test network.
+pub(super) fn is_testnet(deploy: &Deploy, config: &LimitedLedgerConfig) -> bool {
+    config
+        .parser
+        .chains
+        .is_testnet(deploy.header().chain_name())
+}
+
 pub(super) fn message_to_json(
     index: usize,
     sample_msg: Sample<CasperMessage>,
@@ -347,19 +613,8 @@ pub(super) fn message_to_json(
 
     let blob = hex::encode(message.inner());
 
-    let ledger = Ledger::from_message(message);
-    let ledger_view = LimitedLedgerView::new(config, ledger);
-    let output = ledger_view.regular();
-    let output_expert = ledger_view.expert();
-
-    ZondaxRepr {
-        index,
-        name,
-        valid_regular: valid,
-        valid_expert: valid,
-        testnet: true,
-        blob,
-        output,
-        output_expert,
-    }
+    let pages = message_pages(message, config);
+
+    // Messages are not bound to any chain.
+    ZondaxRepr::new(index, name, valid, true, blob, pages)
 }
@@ -1,57 +1,454 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use apdu::Instruction;
+use casper_node::types::Deploy;
+use casper_types::bytesrepr;
+use clap::{Parser, ValueEnum};
+use cli::{
+    ArgsDisplay, ChecksumVectorsArgs, Cli, Command, DeployFormat, Device, DiffArgs, Family,
+    GenerateArgs, GeneratorOpts, RenderArgs, VerifyArgs,
+};
+use diff::{ChangedVector, VectorsDiff};
 use ledger::{LimitedLedgerConfig, ZondaxRepr};
+use message::CasperMessage;
+use sample::Sample;
+use serde::Serialize;
 use test_data::{
-    delegate_samples, generic_samples, native_transfer_samples, redelegate_samples,
-    undelegate_samples,
+    activate_bid_samples, add_bid_samples, amount_samples, cep18_samples, cep78_samples,
+    chain_name_samples, checksum_samples, custom_payment_samples, delegate_samples,
+    generic_args_samples, generic_samples, malformed_samples, native_transfer_samples,
+    page_limit_samples, recipient_samples, redelegate_samples, self_transfer_samples,
+    tampered_samples, undelegate_samples, wasm_samples, withdraw_bid_samples,
 };
 use test_rng::TestRng;
 
 use crate::test_data::sign_message::{invalid_casper_message_sample, valid_casper_message_sample};
 
+mod apdu;
 pub mod checksummed_hex;
+mod cli;
+mod diff;
 mod ledger;
 mod message;
 mod parser;
+mod round_trip;
 mod sample;
 mod test_data;
 mod test_rng;
 mod utils;
+mod verification;
 
-fn main() {
-    let mut rng = TestRng::new();
+/// Sample of any kind that can be turned into a test vector.
+#[derive(Clone)]
+enum AnySample {
+    Deploy(Sample<Deploy>),
+    Message(Sample<CasperMessage>),
+}
 
-    let page_limit = 15;
+impl AnySample {
+    // Prepends `prefix` to the sample's label.
+    fn with_label_prefix(self, prefix: &str) -> Self {
+        match self {
+            AnySample::Deploy(sample) => {
+                let (label, deploy, valid) = sample.destructure();
+                AnySample::Deploy(Sample::new(format!("{}__{}", prefix, label), deploy, valid))
+            }
+            AnySample::Message(sample) => {
+                let (label, message, valid) = sample.destructure();
+                AnySample::Message(Sample::new(
+                    format!("{}__{}", prefix, label),
+                    message,
+                    valid,
+                ))
+            }
+        }
+    }
+}
 
-    let limited_ledger_config = LimitedLedgerConfig::new(page_limit);
+fn family_samples(family: Family, opts: &GeneratorOpts, rng: &mut TestRng) -> Vec<AnySample> {
+    let sample_deploys = match family {
+        Family::Undelegate => undelegate_samples(rng),
+        Family::Delegate => delegate_samples(rng),
+        Family::NativeTransfer => native_transfer_samples(rng),
+        Family::Redelegate => redelegate_samples(rng),
+        Family::Generic => generic_samples(rng),
+        // Page limit samples depend on the device, they are generated separately for each of them.
+        Family::PageLimit => vec![],
+        Family::Malformed => malformed_samples(rng),
+        Family::AddBid => add_bid_samples(rng),
+        Family::WithdrawBid => withdraw_bid_samples(rng),
+        Family::ActivateBid => activate_bid_samples(rng),
+        Family::GenericArgs => {
+            let args_config = opts.args_config();
+            generic_args_samples(rng, args_config.max_count, args_config.max_value_len)
+        }
+        Family::ChainName => chain_name_samples(),
+        Family::Amounts => amount_samples(),
+        Family::Tampered => tampered_samples(),
+        Family::Wasm => wasm_samples(rng),
+        Family::CustomPayment => custom_payment_samples(rng),
+        Family::Cep18 => cep18_samples(rng),
+        Family::Cep78 => cep78_samples(rng),
+        Family::SelfTransfer => self_transfer_samples(),
+        Family::Recipient => recipient_samples(),
+        Family::Message => {
+            return valid_casper_message_sample()
+                .into_iter()
+                .chain(invalid_casper_message_sample())
+                .map(AnySample::Message)
+                .collect()
+        }
+    };
+    sample_deploys.into_iter().map(AnySample::Deploy).collect()
+}
 
-    let mut id = 0;
-    let mut data: Vec<ZondaxRepr> = vec![];
+/// Generates test vectors for the requested sample families, separately for every device.
+fn generate(opts: &GeneratorOpts, devices: &[Device]) -> Vec<Vec<ZondaxRepr>> {
+    let mut rng = opts.rng();
 
-    for sample_deploy in undelegate_samples(&mut rng)
+    // Samples are generated only once so that every device gets the same set of (random) samples.
+    let families: Vec<(Family, Vec<AnySample>)> = opts
+        .families()
         .into_iter()
-        .chain(delegate_samples(&mut rng))
-        .chain(native_transfer_samples(&mut rng))
-        .chain(redelegate_samples(&mut rng))
-        .chain(generic_samples(&mut rng))
-    {
-        data.push(ledger::deploy_to_json(
-            id,
-            sample_deploy,
-            &limited_ledger_config,
-        ));
-        id += 1;
-    }
-
-    for sample_casper_message in valid_casper_message_sample()
+        .map(|family| (family, family_samples(family, opts, &mut rng)))
+        .collect();
+
+    devices
+        .iter()
+        .map(|device| {
+            let profile = device.profile();
+            let limited_ledger_config =
+                LimitedLedgerConfig::new(opts.page_limit, profile, opts.parser_config());
+
+            let mut data: Vec<ZondaxRepr> = vec![];
+            for (family, samples) in &families {
+                let samples: Vec<(AnySample, LimitedLedgerConfig)> = match family {
+                    Family::PageLimit => page_limit_samples(opts.page_limit, |deploy| {
+                        ledger::deploy_page_count(deploy.clone(), false, &limited_ledger_config)
+                            .expect("page limit samples should be parsable")
+                    })
+                    .into_iter()
+                    .map(|sample| (AnySample::Deploy(sample), limited_ledger_config.clone()))
+                    .collect(),
+                    // Every sample is presented in each of the arguments' modes, so that they can be compared.
+                    Family::GenericArgs => ArgsDisplay::value_variants()
+                        .iter()
+                        .flat_map(|args_display| {
+                            let prefix = format!("args_{}", args_display.name().replace('-', "_"));
+                            let config = limited_ledger_config
+                                .with_args(opts.args_config_for(*args_display));
+                            samples.iter().map(move |sample| {
+                                (sample.clone().with_label_prefix(&prefix), config.clone())
+                            })
+                        })
+                        .collect(),
+                    _ => samples
+                        .iter()
+                        .map(|sample| (sample.clone(), limited_ledger_config.clone()))
+                        .collect(),
+                };
+                for (sample, config) in samples {
+                    let (vector, round_trip) = match sample {
+                        AnySample::Deploy(sample_deploy) => {
+                            let mut vector =
+                                ledger::deploy_to_json(data.len(), sample_deploy, &config);
+                            let round_trip = round_trip::check_deploy(&vector, &config);
+                            if opts.apdus {
+                                vector = vector.with_apdus(Instruction::SignDeploy);
+                            }
+                            (vector, round_trip)
+                        }
+                        AnySample::Message(sample_casper_message) => {
+                            let mut vector =
+                                ledger::message_to_json(data.len(), sample_casper_message, &config);
+                            let round_trip = round_trip::check_message(&vector, &config);
+                            if opts.apdus {
+                                vector = vector.with_apdus(Instruction::SignMessage);
+                            }
+                            (vector, round_trip)
+                        }
+                    };
+                    if let Err(error) = round_trip {
+                        panic!(
+                            "test vector #{} ({}) doesn't round-trip: {}",
+                            vector.index(),
+                            vector.name(),
+                            error
+                        );
+                    }
+                    data.push(vector);
+                }
+            }
+            data
+        })
+        .collect()
+}
+
+/// Generates test vectors for a single device.
+fn generate_for(opts: &GeneratorOpts, device: Device) -> Vec<ZondaxRepr> {
+    generate(opts, &[device]).remove(0)
+}
+
+fn to_json(data: &[ZondaxRepr]) -> String {
+    format!("{}\n", serde_json::to_string_pretty(data).unwrap())
+}
+
+// Returns path of the output file for the given device.
+// Device name is appended only when generating for multiple devices.
+fn device_output_path(path: &Path, device: Device, devices_count: usize) -> PathBuf {
+    if devices_count == 1 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}_{}.{}", stem, device.name(), extension.to_string_lossy()),
+        None => format!("{}_{}", stem, device.name()),
+    };
+    path.with_file_name(file_name)
+}
+
+fn run_generate(args: GenerateArgs) {
+    let data = generate(&args.opts, &args.devices);
+    match args.output {
+        Some(path) => {
+            for (device, vectors) in args.devices.iter().zip(data.iter()) {
+                let path = device_output_path(&path, *device, args.devices.len());
+                std::fs::write(&path, to_json(vectors)).unwrap_or_else(|err| {
+                    exit_with_error(format!("can't write to {}: {}", path.display(), err))
+                });
+            }
+        }
+        None if args.devices.len() == 1 => print!("{}", to_json(&data[0])),
+        None => {
+            // Multiple devices are printed as a single JSON object, keyed by the device name.
+            let by_device: BTreeMap<String, &Vec<ZondaxRepr>> = args
+                .devices
+                .iter()
+                .map(Device::name)
+                .zip(data.iter())
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&by_device).unwrap());
+        }
+    }
+}
+
+/// Reads `Deploy` from a file, either as JSON or as hex-encoded bytes.
+fn read_deploy(path: &Path, format: DeployFormat) -> Deploy {
+    let input = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| exit_with_error(format!("can't read {}: {}", path.display(), err)));
+    match format {
+        DeployFormat::Json => {
+            let value: serde_json::Value = serde_json::from_str(&input).unwrap_or_else(|err| {
+                exit_with_error(format!("can't parse {}: {}", path.display(), err))
+            });
+            deploy_from_json(value)
+                .unwrap_or_else(|err| exit_with_error(format!("can't parse deploy JSON: {}", err)))
+        }
+        DeployFormat::Hex => {
+            let input = input.trim();
+            let bytes = hex::decode(input.strip_prefix("0x").unwrap_or(input))
+                .unwrap_or_else(|err| exit_with_error(format!("can't decode hex: {}", err)));
+            bytesrepr::deserialize(bytes).unwrap_or_else(|err| {
+                exit_with_error(format!("can't deserialize deploy bytes: {}", err))
+            })
+        }
+    }
+}
+
+/// Extracts `Deploy` from its JSON, as returned by casper-node or casper-client.
+///
+/// Node's `info_get_deploy` result wraps the deploy in a `deploy` field,
+/// and `casper-client get-deploy` prints the whole JSON-RPC response, with the result in a `result` field.
+fn deploy_from_json(mut value: serde_json::Value) -> serde_json::Result<Deploy> {
+    if let Some(result) = value.get_mut("result") {
+        value = result.take();
+    }
+    if let Some(deploy) = value.get_mut("deploy") {
+        value = deploy.take();
+    }
+    serde_json::from_value(value)
+}
+
+// Prints the `message` and exits with a non-zero status.
+fn exit_with_error(message: String) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
+
+fn print_pages(output: &[String], output_expert: &[String]) {
+    println!("Regular:");
+    output.iter().for_each(|page| println!("{}", page));
+    println!("Expert:");
+    output_expert.iter().for_each(|page| println!("{}", page));
+}
+
+fn run_render(args: RenderArgs) {
+    if let Some(path) = &args.deploy {
+        let deploy = read_deploy(path, args.format);
+        let config = LimitedLedgerConfig::new(
+            args.opts.page_limit,
+            args.device.profile(),
+            args.opts.parser_config(),
+        );
+        match ledger::deploy_pages(deploy, &config) {
+            Ok((output, output_expert)) => print_pages(&output, &output_expert),
+            Err(error) => exit_with_error(format!("can't parse deploy: {}", error)),
+        }
+        return;
+    }
+
+    let index = args.index.expect("either index or deploy is required");
+    let data = generate_for(&args.opts, args.device);
+    let vector = data.get(index).unwrap_or_else(|| {
+        exit_with_error(format!(
+            "index {} out of range, generated {} test vectors",
+            index,
+            data.len()
+        ))
+    });
+    println!("{}", vector.name());
+    print_pages(vector.output(), vector.output_expert());
+}
+
+fn run_verify(args: VerifyArgs) {
+    let expected = read_vectors(&args.input);
+    let generated = generate_for(&args.opts, args.device);
+
+    let mut mismatches = 0;
+    for (expected, generated) in expected.iter().zip(generated.iter()) {
+        if expected != generated {
+            eprintln!(
+                "test vector #{} ({}) differs",
+                expected.index(),
+                expected.name()
+            );
+            mismatches += 1;
+        }
+    }
+    if expected.len() != generated.len() {
+        eprintln!(
+            "expected {} test vectors, generated {}",
+            expected.len(),
+            generated.len()
+        );
+        mismatches += 1;
+    }
+
+    if mismatches > 0 {
+        std::process::exit(1);
+    }
+    println!("{} test vectors match", generated.len());
+}
+
+fn read_vectors(path: &Path) -> Vec<ZondaxRepr> {
+    let input = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| exit_with_error(format!("can't read {}: {}", path.display(), err)));
+    serde_json::from_str(&input)
+        .unwrap_or_else(|err| exit_with_error(format!("can't parse {}: {}", path.display(), err)))
+}
+
+fn run_diff(args: DiffArgs) {
+    let old = read_vectors(&args.old);
+    let new = read_vectors(&args.new);
+    let vectors_diff = VectorsDiff::new(&old, &new);
+    println!("{}", vectors_diff);
+
+    if args.allow_all {
+        return;
+    }
+    let disallowed = vectors_diff
+        .removed
+        .iter()
+        .copied()
+        .chain(vectors_diff.changed.iter().map(ChangedVector::old))
+        .filter(|vector| !args.allow.iter().any(|name| name == vector.name()))
+        .count();
+    if disallowed > 0 {
+        eprintln!(
+            "{} of the old test vectors were removed or changed, this breaks backwards compatibility",
+            disallowed
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Test vector of the checksummed hex encoding.
+#[derive(Serialize)]
+struct ChecksumVector {
+    index: usize,
+    name: String,
+    /// Lowercase hex of the input bytes.
+    bytes: String,
+    /// Checksummed hex of the input bytes.
+    checksummed: String,
+}
+
+fn run_checksum_vectors(args: ChecksumVectorsArgs) {
+    let vectors: Vec<ChecksumVector> = checksum_samples()
         .into_iter()
-        .chain(invalid_casper_message_sample())
-    {
-        data.push(ledger::message_to_json(
-            id,
-            sample_casper_message,
-            &limited_ledger_config,
-        ));
-        id += 1;
+        .enumerate()
+        .map(|(index, sample)| {
+            let (name, bytes, _valid) = sample.destructure();
+            let checksummed = checksummed_hex::encode(&bytes);
+            let decoded = checksummed_hex::decode(&checksummed)
+                .unwrap_or_else(|err| panic!("can't decode {}: {}", checksummed, err));
+            assert_eq!(bytes, decoded, "{} doesn't round-trip", name);
+            ChecksumVector {
+                index,
+                name,
+                bytes: hex::encode(&bytes),
+                checksummed,
+            }
+        })
+        .collect();
+    let json = format!("{}\n", serde_json::to_string_pretty(&vectors).unwrap());
+    match args.output {
+        Some(path) => std::fs::write(&path, json).unwrap_or_else(|err| {
+            exit_with_error(format!("can't write to {}: {}", path.display(), err))
+        }),
+        None => print!("{}", json),
     }
+}
 
-    println!("{}", serde_json::to_string_pretty(&data).unwrap());
+fn main() {
+    match Cli::parse().command {
+        Command::Generate(args) => run_generate(args),
+        Command::Render(args) => run_render(args),
+        Command::Verify(args) => run_verify(args),
+        Command::Diff(args) => run_diff(args),
+        Command::ChecksumVectors(args) => run_checksum_vectors(args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::deploy_from_json;
+    use crate::test_data::chain_name_samples;
+
+    #[test]
+    fn deploy_from_json_unwraps_rpc_responses() {
+        let (_, deploy, _) = chain_name_samples().remove(0).destructure();
+        let deploy_json = serde_json::to_value(&deploy).unwrap();
+
+        let info_get_deploy_result = json!({
+            "api_version": "1.5.6",
+            "deploy": deploy_json,
+            "execution_results": [],
+        });
+        // As printed by `casper-client get-deploy`.
+        let rpc_response = json!({
+            "jsonrpc": "2.0",
+            "id": -2_035_588_410_512_004_610i64,
+            "result": info_get_deploy_result,
+        });
+
+        for value in [deploy_json, info_get_deploy_result, rpc_response] {
+            assert_eq!(deploy, deploy_from_json(value).unwrap());
+        }
+    }
 }
@@ -1,9 +1,57 @@
+use std::fmt::{self, Display, Formatter};
+
 use casper_types::{blake2b, BLAKE2B_DIGEST_LENGTH};
 
 /// It became a de-facto standard in Casper network that messsages for signing
 /// are prepended with the following prefix.
 const MSG_PREFIX: &str = "Casper Message:\n";
 
+/// Maximum length of the message (including the prefix) that can be signed.
+pub(crate) const MAX_MESSAGE_LENGTH: usize = 1024;
+
+/// Rules a message for signing can break.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MessageError {
+    /// Message doesn't start with the exact `Casper Message:\n` prefix.
+    MissingPrefix,
+    /// Message is longer than `MAX_MESSAGE_LENGTH` bytes.
+    TooLong { length: usize },
+    /// There's nothing after the prefix.
+    EmptyBody,
+    /// Message after the prefix is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl Display for MessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::MissingPrefix => write!(f, "missing {:?} prefix", MSG_PREFIX),
+            MessageError::TooLong { length } => write!(
+                f,
+                "message is {} bytes long, at most {} allowed",
+                length, MAX_MESSAGE_LENGTH
+            ),
+            MessageError::EmptyBody => write!(f, "empty message"),
+            MessageError::InvalidUtf8 => write!(f, "message is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for MessageError {}
+
+impl MessageError {
+    /// Short name of the broken rule, used in the names of the test vectors.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            MessageError::MissingPrefix => "missing_prefix",
+            MessageError::TooLong { .. } => "too_long",
+            MessageError::EmptyBody => "empty_body",
+            MessageError::InvalidUtf8 => "invalid_utf8",
+        }
+    }
+}
+
+#[derive(Clone)]
 pub(crate) struct CasperMessage(Vec<u8>);
 
 impl CasperMessage {
@@ -17,6 +65,25 @@ impl CasperMessage {
         CasperMessage(output)
     }
 
+    /// Parses the message for signing, checking that it follows all of the rules:
+    /// starts with the exact prefix, is not too long, is not empty and is valid UTF-8.
+    pub(crate) fn parse(msg: Vec<u8>) -> Result<Self, MessageError> {
+        let message = CasperMessage(msg);
+        let body = message.body().ok_or(MessageError::MissingPrefix)?;
+        if message.0.len() > MAX_MESSAGE_LENGTH {
+            return Err(MessageError::TooLong {
+                length: message.0.len(),
+            });
+        }
+        if body.is_empty() {
+            return Err(MessageError::EmptyBody);
+        }
+        if std::str::from_utf8(body).is_err() {
+            return Err(MessageError::InvalidUtf8);
+        }
+        Ok(message)
+    }
+
     /// Bypasses the valid header prefix.
     ///
     /// WARNING: Allows for creating invalid instances of `CasperMessage`.
@@ -29,8 +96,53 @@ impl CasperMessage {
         &self.0
     }
 
+    /// Returns the message without the prefix, or `None` if it doesn't start with the prefix.
+    pub(crate) fn body(&self) -> Option<&[u8]> {
+        self.0.strip_prefix(MSG_PREFIX.as_bytes())
+    }
+
     /// Returns blake2b hash of the underlying bytes.
     pub(crate) fn hashed(&self) -> [u8; BLAKE2B_DIGEST_LENGTH] {
         blake2b(&self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CasperMessage, MessageError, MAX_MESSAGE_LENGTH, MSG_PREFIX};
+
+    fn prefixed(body: &[u8]) -> Vec<u8> {
+        let mut msg = MSG_PREFIX.as_bytes().to_vec();
+        msg.extend(body);
+        msg
+    }
+
+    #[test]
+    fn parse_valid() {
+        let message = CasperMessage::parse(prefixed(b"Hello")).unwrap();
+        assert_eq!(Some(&b"Hello"[..]), message.body());
+    }
+
+    #[test]
+    fn parse_rejects_broken_rules() {
+        assert_eq!(
+            Err(MessageError::MissingPrefix),
+            CasperMessage::parse(b"casper message:\nHello".to_vec()).map(|_| ())
+        );
+        assert_eq!(
+            Err(MessageError::EmptyBody),
+            CasperMessage::parse(prefixed(b"")).map(|_| ())
+        );
+        assert_eq!(
+            Err(MessageError::InvalidUtf8),
+            CasperMessage::parse(prefixed(&[0xff, 0xfe])).map(|_| ())
+        );
+        let too_long = prefixed(&[b'a'; MAX_MESSAGE_LENGTH]);
+        assert_eq!(
+            Err(MessageError::TooLong {
+                length: too_long.len()
+            }),
+            CasperMessage::parse(too_long).map(|_| ())
+        );
+    }
+}
@@ -1,46 +1,89 @@
 mod auction;
+mod cep18;
+mod cep78;
+mod chain;
 mod deploy;
+mod error;
+mod message;
+mod recognizer;
 mod runtime_args;
 mod utils;
 
+pub(crate) use chain::KnownChains;
+pub(crate) use deploy::MAX_LISTED_SIGNERS;
+pub(crate) use error::ParseError;
+pub(crate) use message::parse_message;
+pub(crate) use runtime_args::{ArgsConfig, ArgsMode, MAX_LISTED_ARGS};
+
+use std::rc::Rc;
+
 use casper_node::types::Deploy;
 
 use crate::{
     checksummed_hex,
     ledger::{Element, TxnPhase},
-    message::CasperMessage,
-    parser::deploy::{parse_approvals, parse_deploy_header, parse_phase},
+    parser::{
+        deploy::{parse_approvals, parse_deploy_header, parse_phase},
+        recognizer::{Context, Recognizers},
+    },
 };
 
-pub(crate) fn parse_message(m: CasperMessage) -> Vec<Element> {
-    vec![Element::regular("Msg hash", hex::encode(m.hashed()))]
+/// Configuration of the `Deploy` presentation.
+#[derive(Clone, Debug)]
+pub(crate) struct ParserConfig {
+    pub(crate) args: ArgsConfig,
+    pub(crate) chains: KnownChains,
+    /// Maximum number of approvals' signers that are listed.
+    pub(crate) max_signers: usize,
+    /// Display rules for the calls to known contracts.
+    pub(crate) recognizers: Rc<Recognizers>,
+}
+
+impl ParserConfig {
+    pub(crate) fn new(args: ArgsConfig, chains: KnownChains, max_signers: usize) -> Self {
+        ParserConfig {
+            args,
+            chains,
+            max_signers,
+            recognizers: Rc::new(Recognizers::builtin()),
+        }
+    }
 }
 
-pub(crate) fn parse_deploy(d: Deploy) -> Vec<Element> {
+pub(crate) fn parse_deploy(d: Deploy, config: &ParserConfig) -> Result<Vec<Element>, ParseError> {
     let mut elements = vec![];
     elements.push(Element::regular(
         "Txn hash",
         format!("{}", checksummed_hex::encode(d.hash().inner())),
     ));
-    elements.push(deploy_type(&d));
-    elements.extend(parse_deploy_header(d.header()));
-    elements.extend(parse_phase(d.payment(), TxnPhase::Payment));
-    elements.extend(parse_phase(d.session(), TxnPhase::Session));
-    elements.extend(parse_approvals(&d));
-    elements
+    elements.push(deploy_type(&d, config)?);
+    elements.extend(parse_deploy_header(d.header(), &config.chains)?);
+    elements.extend(parse_phase(
+        d.payment(),
+        TxnPhase::Payment,
+        d.header().account(),
+        config,
+    )?);
+    elements.extend(parse_phase(
+        d.session(),
+        TxnPhase::Session,
+        d.header().account(),
+        config,
+    )?);
+    elements.extend(parse_approvals(&d, config.max_signers)?);
+    Ok(elements)
 }
 
-fn deploy_type(d: &Deploy) -> Element {
-    let dtype = if auction::is_delegate(d.session()) {
-        "Delegate"
-    } else if auction::is_undelegate(d.session()) {
-        "Undelegate"
-    } else if auction::is_redelegate(d.session()) {
-        "Redelegate"
-    } else if d.session().is_transfer() {
-        "Token transfer"
-    } else {
-        "Contract execution"
+fn deploy_type(d: &Deploy, config: &ParserConfig) -> Result<Element, ParseError> {
+    let context = Context {
+        phase: TxnPhase::Session,
+        account: d.header().account(),
+        args_config: &config.args,
     };
-    Element::regular("Type", dtype.to_string())
+    let dtype = config
+        .recognizers
+        .recognize(d.session(), &context)?
+        .and_then(|recognized| recognized.type_label)
+        .unwrap_or_else(|| "Contract execution".to_string());
+    Ok(Element::regular("Type", dtype))
 }
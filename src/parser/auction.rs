@@ -1,88 +1,139 @@
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_types::{system::mint, RuntimeArgs};
+use casper_types::{system::mint, CLType, RuntimeArgs};
 
 use crate::{
     ledger::{Element, TxnPhase},
     parser::deploy::{deploy_type, parse_amount},
 };
 
-use super::{deploy::identity, runtime_args::parse_optional_arg};
+use super::{runtime_args::parse_optional_arg, utils::has_arg, ParseError};
 
-fn parse_auction_item<'a, F>(
+fn parse_auction_item<F>(
     method: &str,
-    item: &'a ExecutableDeployItem,
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
     args_parser: F,
-) -> Vec<Element>
+) -> Result<Vec<Element>, ParseError>
 where
-    F: Fn(&'a RuntimeArgs) -> Vec<Element>,
+    F: Fn(&RuntimeArgs) -> Result<Vec<Element>, ParseError>,
 {
     let mut elements = vec![];
-    elements.extend(
-        deploy_type(TxnPhase::Session, item)
-            .into_iter()
-            .map(|mut e| {
-                // For now, we choose to not display deploy's details for delegation.
-                e.as_expert();
-                e
-            }),
-    );
+    elements.extend(deploy_type(phase, item).into_iter().map(|mut e| {
+        // For now, we choose to not display deploy's details for delegation.
+        e.as_expert();
+        e
+    }));
     match item {
         ExecutableDeployItem::Transfer { .. } => {
-            panic!("unexpected type for {}", method)
+            return Err(ParseError::UnexpectedTransfer {
+                method: method.to_string(),
+            })
         }
         ExecutableDeployItem::StoredContractByHash { args, .. }
         | ExecutableDeployItem::StoredContractByName { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
         | ExecutableDeployItem::ModuleBytes { args, .. } => {
-            elements.extend(args_parser(args));
+            elements.extend(args_parser(args)?);
         }
     };
-    elements
+    Ok(elements)
 }
 
-pub(crate) fn parse_delegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_delegation(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args).into_iter());
+        elements.extend(parse_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args)?.into_iter());
+        Ok(elements)
     };
-    parse_auction_item("delegate", item, arg_parser)
+    parse_auction_item("delegate", item, phase, arg_parser)
 }
 
-pub(crate) fn parse_undelegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_undelegation(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args).into_iter());
+        elements.extend(parse_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args)?.into_iter());
+        Ok(elements)
     };
-    parse_auction_item("undelegate", item, arg_parser)
+    parse_auction_item("undelegate", item, phase, arg_parser)
 }
 
-pub(crate) fn parse_redelegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_redelegation(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the current validator we have been redelagating to so far.
-        elements.extend(parse_old_validator(args).into_iter());
+        elements.extend(parse_old_validator(args)?.into_iter());
         // New validator we're redelegating to.
-        elements.extend(parse_new_validator(args).into_iter());
+        elements.extend(parse_new_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args)?.into_iter());
+        Ok(elements)
     };
-    parse_auction_item("redelegate", item, arg_parser)
+    parse_auction_item("redelegate", item, phase, arg_parser)
+}
+
+pub(crate) fn parse_add_bid(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Public key of the validator placing the bid.
+        elements.extend(parse_bidder(args)?.into_iter());
+        // Percentage of the rewards the validator keeps.
+        elements.extend(parse_delegation_rate(args)?.into_iter());
+        // Amount we're bidding.
+        elements.extend(parse_amount(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_auction_item("add_bid", item, phase, arg_parser)
+}
+
+pub(crate) fn parse_withdraw_bid(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Public key of the validator withdrawing the bid.
+        elements.extend(parse_bidder(args)?.into_iter());
+        // Amount we're withdrawing.
+        elements.extend(parse_amount(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_auction_item("withdraw_bid", item, phase, arg_parser)
+}
+
+pub(crate) fn parse_activate_bid(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        // Public key of the validator whose (evicted) bid we're reactivating.
+        Ok(parse_validator_public_key(args)?.into_iter().collect())
+    };
+    parse_auction_item("activate_bid", item, phase, arg_parser)
 }
 
 /// Returns `true` when the deploy's entry point is *literally* _delegate_
@@ -103,43 +154,68 @@ pub(crate) fn is_redelegate(item: &ExecutableDeployItem) -> bool {
         && has_redelegate_arg(item)
 }
 
+/// Returns `true` when the deploy's entry point is *literally* _add_bid_
+pub(crate) fn is_add_bid(item: &ExecutableDeployItem) -> bool {
+    (is_entrypoint(item, ADD_BID_ENTRYPOINT) || has_auction_arg(item, ADD_BID_ENTRYPOINT))
+        && has_add_bid_args(item)
+}
+
+/// Returns `true` when the deploy's entry point is *literally* _withdraw_bid_
+pub(crate) fn is_withdraw_bid(item: &ExecutableDeployItem) -> bool {
+    (is_entrypoint(item, WITHDRAW_BID_ENTRYPOINT) || has_auction_arg(item, WITHDRAW_BID_ENTRYPOINT))
+        && has_withdraw_bid_args(item)
+}
+
+/// Returns `true` when the deploy's entry point is *literally* _activate_bid_
+pub(crate) fn is_activate_bid(item: &ExecutableDeployItem) -> bool {
+    (is_entrypoint(item, ACTIVATE_BID_ENTRYPOINT) || has_auction_arg(item, ACTIVATE_BID_ENTRYPOINT))
+        && has_activate_bid_args(item)
+}
+
+// Deploys with an `auction` argument of another type are not auction calls. They are displayed
+// as generic transactions, dApps may use such arguments for their own purposes.
 fn get_auction_arg(item: &ExecutableDeployItem) -> Option<String> {
     match item {
         // ModuleBytes variant does not have an entry point, it defaults to `call()`,
         // so we expect a special named argument called `auction` when detecting auction contract calls.
-        ExecutableDeployItem::ModuleBytes { args, .. } => args.get("auction").map(|cl_value| {
-            cl_value
-                .clone()
-                .into_t::<String>()
-                .expect("argument should be string")
-        }),
+        ExecutableDeployItem::ModuleBytes { args, .. } => args
+            .get(AUCTION_ARG_KEY)
+            .and_then(|cl_value| cl_value.clone().into_t::<String>().ok()),
         _ => None,
     }
 }
 
+const AUCTION_ARG_KEY: &str = "auction";
 const DELEGATE_ENTRYPOINT: &str = "delegate";
 const UNDELEGATE_ENTRYPOINT: &str = "undelegate";
 const REDELEGATE_ENTRYPOINT: &str = "redelegate";
 const DELEGATOR_ARG_KEY: &str = "delegator";
 const VALIDATOR_ARG_KEY: &str = "validator";
 const NEW_VALIDATOR_ARG_KEY: &str = "new_validator";
+const ADD_BID_ENTRYPOINT: &str = "add_bid";
+const WITHDRAW_BID_ENTRYPOINT: &str = "withdraw_bid";
+const ACTIVATE_BID_ENTRYPOINT: &str = "activate_bid";
+const PUBLIC_KEY_ARG_KEY: &str = "public_key";
+const DELEGATION_RATE_ARG_KEY: &str = "delegation_rate";
+const VALIDATOR_PUBLIC_KEY_ARG_KEY: &str = "validator_public_key";
 
-fn has_delegate_auction_arg(item: &ExecutableDeployItem) -> bool {
+// Returns `true` when the `auction` argument names the `entrypoint`.
+fn has_auction_arg(item: &ExecutableDeployItem, entrypoint: &str) -> bool {
     get_auction_arg(item)
-        .filter(|arg_value| arg_value.to_lowercase() == DELEGATE_ENTRYPOINT)
+        .filter(|arg_value| arg_value.to_lowercase() == entrypoint)
         .is_some()
 }
 
+fn has_delegate_auction_arg(item: &ExecutableDeployItem) -> bool {
+    has_auction_arg(item, DELEGATE_ENTRYPOINT)
+}
+
 fn has_undelegate_auction_arg(item: &ExecutableDeployItem) -> bool {
-    get_auction_arg(item)
-        .filter(|arg_value| arg_value.to_lowercase() == UNDELEGATE_ENTRYPOINT)
-        .is_some()
+    has_auction_arg(item, UNDELEGATE_ENTRYPOINT)
 }
 
 fn has_redelegate_auction_arg(item: &ExecutableDeployItem) -> bool {
-    get_auction_arg(item)
-        .filter(|arg_value| arg_value.to_lowercase() == REDELEGATE_ENTRYPOINT)
-        .is_some()
+    has_auction_arg(item, REDELEGATE_ENTRYPOINT)
 }
 
 fn has_delegate_args(item: &ExecutableDeployItem) -> bool {
@@ -161,20 +237,61 @@ fn has_redelegate_arg(item: &ExecutableDeployItem) -> bool {
         && item.args().get(mint::ARG_AMOUNT).is_some()
 }
 
-fn parse_delegator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, DELEGATOR_ARG_KEY, "delegator", false, identity)
+// Bid arguments have to be of the types the auction contract expects,
+// otherwise the call is displayed as a generic one.
+fn has_add_bid_args(item: &ExecutableDeployItem) -> bool {
+    has_arg(item.args(), PUBLIC_KEY_ARG_KEY, &CLType::PublicKey)
+        && has_arg(item.args(), DELEGATION_RATE_ARG_KEY, &CLType::U8)
+        && has_arg(item.args(), mint::ARG_AMOUNT, &CLType::U512)
+}
+
+fn has_withdraw_bid_args(item: &ExecutableDeployItem) -> bool {
+    has_arg(item.args(), PUBLIC_KEY_ARG_KEY, &CLType::PublicKey)
+        && has_arg(item.args(), mint::ARG_AMOUNT, &CLType::U512)
+}
+
+fn has_activate_bid_args(item: &ExecutableDeployItem) -> bool {
+    has_arg(
+        item.args(),
+        VALIDATOR_PUBLIC_KEY_ARG_KEY,
+        &CLType::PublicKey,
+    )
+}
+
+fn parse_delegator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, DELEGATOR_ARG_KEY, "delegator", false, Ok)
+}
+
+fn parse_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, VALIDATOR_ARG_KEY, "validator", false, Ok)
+}
+
+fn parse_old_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, VALIDATOR_ARG_KEY, "old", false, Ok)
+}
+
+fn parse_new_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, NEW_VALIDATOR_ARG_KEY, "new", false, Ok)
 }
 
-fn parse_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "validator", false, identity)
+// Bids are placed by validators, so the bidder's public key is labeled as such.
+fn parse_bidder(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, PUBLIC_KEY_ARG_KEY, "validator", false, Ok)
 }
 
-fn parse_old_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "old", false, identity)
+fn parse_validator_public_key(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, VALIDATOR_PUBLIC_KEY_ARG_KEY, "validator", false, Ok)
 }
 
-fn parse_new_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, NEW_VALIDATOR_ARG_KEY, "new", false, identity)
+// Delegation rate is a percentage of the validator's rewards it keeps for itself.
+fn parse_delegation_rate(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(
+        args,
+        DELEGATION_RATE_ARG_KEY,
+        "deleg. rate",
+        false,
+        |rate| Ok(format!("{}%", rate)),
+    )
 }
 
 fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
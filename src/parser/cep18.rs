@@ -0,0 +1,122 @@
+//! Recognition of the CEP-18 (fungible token) contract calls.
+//!
+//! Tokens are stored contracts, so only calls to their entry points are recognized.
+//! The arguments have to have exactly the types from the standard, otherwise
+//! the call is presented as a generic contract execution.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, RuntimeArgs};
+
+use crate::ledger::{Element, TxnPhase};
+
+use super::{
+    runtime_args::parse_optional_arg,
+    utils::{has_arg, is_entrypoint, parse_contract_call},
+    ParseError,
+};
+
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const APPROVE_ENTRYPOINT: &str = "approve";
+const TRANSFER_FROM_ENTRYPOINT: &str = "transfer_from";
+const RECIPIENT_ARG_KEY: &str = "recipient";
+const SPENDER_ARG_KEY: &str = "spender";
+const OWNER_ARG_KEY: &str = "owner";
+const AMOUNT_ARG_KEY: &str = "amount";
+
+pub(crate) fn parse_cep18_transfer(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Account or contract receiving the tokens.
+        elements.extend(parse_recipient(args)?.into_iter());
+        // Amount of tokens, in the token's smallest units.
+        elements.extend(parse_raw_amount(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_contract_call(TRANSFER_ENTRYPOINT, item, phase, arg_parser)
+}
+
+pub(crate) fn parse_cep18_approve(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Account or contract allowed to spend the tokens.
+        elements.extend(parse_spender(args)?.into_iter());
+        // Amount of tokens it's allowed to spend, in the token's smallest units.
+        elements.extend(parse_raw_amount(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_contract_call(APPROVE_ENTRYPOINT, item, phase, arg_parser)
+}
+
+pub(crate) fn parse_cep18_transfer_from(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Account whose tokens, previously approved, are transferred.
+        elements.extend(parse_owner(args)?.into_iter());
+        // Account or contract receiving the tokens.
+        elements.extend(parse_recipient(args)?.into_iter());
+        // Amount of tokens, in the token's smallest units.
+        elements.extend(parse_raw_amount(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_contract_call(TRANSFER_FROM_ENTRYPOINT, item, phase, arg_parser)
+}
+
+/// Returns `true` when the deploy calls CEP-18 _transfer_ with `recipient` and `amount`.
+pub(crate) fn is_cep18_transfer(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, TRANSFER_ENTRYPOINT) && has_args(item, &[(RECIPIENT_ARG_KEY, CLType::Key)])
+}
+
+/// Returns `true` when the deploy calls CEP-18 _approve_ with `spender` and `amount`.
+pub(crate) fn is_cep18_approve(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, APPROVE_ENTRYPOINT) && has_args(item, &[(SPENDER_ARG_KEY, CLType::Key)])
+}
+
+/// Returns `true` when the deploy calls CEP-18 _transfer_from_ with `owner`, `recipient` and `amount`.
+pub(crate) fn is_cep18_transfer_from(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, TRANSFER_FROM_ENTRYPOINT)
+        && has_args(
+            item,
+            &[
+                (OWNER_ARG_KEY, CLType::Key),
+                (RECIPIENT_ARG_KEY, CLType::Key),
+            ],
+        )
+}
+
+// Every CEP-18 call takes `amount: U256`, in addition to the `expected` arguments.
+// There must be no other arguments.
+fn has_args(item: &ExecutableDeployItem, expected: &[(&str, CLType)]) -> bool {
+    let args = item.args();
+    args.len() == expected.len() + 1
+        && has_arg(args, AMOUNT_ARG_KEY, &CLType::U256)
+        && expected
+            .iter()
+            .all(|(name, cl_type)| has_arg(args, name, cl_type))
+}
+
+fn parse_recipient(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, RECIPIENT_ARG_KEY, "recipient", false, Ok)
+}
+
+fn parse_spender(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, SPENDER_ARG_KEY, "spender", false, Ok)
+}
+
+fn parse_owner(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, OWNER_ARG_KEY, "owner", false, Ok)
+}
+
+// Token's decimals are stored in the contract, not in the deploy, so the amount can't be
+// formatted like CSPR amounts are.
+fn parse_raw_amount(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, AMOUNT_ARG_KEY, "raw amount", false, Ok)
+}
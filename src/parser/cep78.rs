@@ -0,0 +1,185 @@
+//! Recognition of the CEP-78 (NFT) contract calls.
+//!
+//! Tokens are identified either by their ordinal `token_id: u64` or by `token_hash: String`,
+//! depending on the collection's identifier mode. Calls with any other arguments than the ones
+//! from the standard are presented as generic contract executions.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_types::{CLType, RuntimeArgs};
+
+use crate::{
+    checksummed_hex,
+    ledger::{Element, TxnPhase},
+};
+
+use super::{
+    runtime_args::parse_optional_arg,
+    utils::{has_arg, is_entrypoint, parse_contract_call},
+    ParseError,
+};
+
+const MINT_ENTRYPOINT: &str = "mint";
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const BURN_ENTRYPOINT: &str = "burn";
+const APPROVE_ENTRYPOINT: &str = "approve";
+const TOKEN_ID_ARG_KEY: &str = "token_id";
+const TOKEN_HASH_ARG_KEY: &str = "token_hash";
+const TOKEN_OWNER_ARG_KEY: &str = "token_owner";
+const TOKEN_META_DATA_ARG_KEY: &str = "token_meta_data";
+const SOURCE_KEY_ARG_KEY: &str = "source_key";
+const TARGET_KEY_ARG_KEY: &str = "target_key";
+const SPENDER_ARG_KEY: &str = "spender";
+
+pub(crate) fn parse_cep78_mint(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        // Account or contract the new token belongs to.
+        elements.extend(parse_token_owner(args)?.into_iter());
+        // Present only when tokens are identified by their hashes.
+        elements.extend(parse_token_id(args)?.into_iter());
+        // Metadata can be arbitrarily long, so only its hash is displayed.
+        elements.extend(parse_meta_data_hash(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_contract_call(MINT_ENTRYPOINT, item, phase, arg_parser)
+}
+
+pub(crate) fn parse_cep78_transfer(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        elements.extend(parse_token_id(args)?.into_iter());
+        // Current owner of the token.
+        elements.extend(parse_optional_arg(
+            args,
+            SOURCE_KEY_ARG_KEY,
+            "source",
+            false,
+            Ok,
+        )?);
+        // New owner of the token.
+        elements.extend(parse_optional_arg(
+            args,
+            TARGET_KEY_ARG_KEY,
+            "target",
+            false,
+            Ok,
+        )?);
+        Ok(elements)
+    };
+    parse_contract_call(TRANSFER_ENTRYPOINT, item, phase, arg_parser)
+}
+
+pub(crate) fn parse_cep78_burn(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        Ok(parse_token_id(args)?.into_iter().collect())
+    };
+    parse_contract_call(BURN_ENTRYPOINT, item, phase, arg_parser)
+}
+
+pub(crate) fn parse_cep78_approve(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args: &RuntimeArgs| -> Result<Vec<Element>, ParseError> {
+        let mut elements = vec![];
+        elements.extend(parse_token_id(args)?.into_iter());
+        // Account or contract allowed to transfer the token.
+        elements.extend(parse_optional_arg(
+            args,
+            SPENDER_ARG_KEY,
+            "spender",
+            false,
+            Ok,
+        )?);
+        Ok(elements)
+    };
+    parse_contract_call(APPROVE_ENTRYPOINT, item, phase, arg_parser)
+}
+
+/// Returns `true` when the deploy calls CEP-78 _mint_ with `token_owner` and `token_meta_data`,
+/// and optionally the `token_hash`.
+pub(crate) fn is_cep78_mint(item: &ExecutableDeployItem) -> bool {
+    let args = item.args();
+    // `token_hash` is passed only when the collection identifies tokens by their hashes.
+    let (identifier_count, valid_identifier) = match args.get(TOKEN_HASH_ARG_KEY) {
+        Some(_) => (1, has_arg(args, TOKEN_HASH_ARG_KEY, &CLType::String)),
+        None => (0, true),
+    };
+    is_entrypoint(item, MINT_ENTRYPOINT)
+        && valid_identifier
+        && args.len() == identifier_count + 2
+        && has_arg(args, TOKEN_OWNER_ARG_KEY, &CLType::Key)
+        && has_arg(args, TOKEN_META_DATA_ARG_KEY, &CLType::String)
+}
+
+/// Returns `true` when the deploy calls CEP-78 _transfer_ with the token's identifier,
+/// `source_key` and `target_key`.
+pub(crate) fn is_cep78_transfer(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, TRANSFER_ENTRYPOINT)
+        && has_token_args(item, &[SOURCE_KEY_ARG_KEY, TARGET_KEY_ARG_KEY])
+}
+
+/// Returns `true` when the deploy calls CEP-78 _burn_ with the token's identifier.
+pub(crate) fn is_cep78_burn(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, BURN_ENTRYPOINT) && has_token_args(item, &[])
+}
+
+/// Returns `true` when the deploy calls CEP-78 _approve_ with the token's identifier and `spender`.
+pub(crate) fn is_cep78_approve(item: &ExecutableDeployItem) -> bool {
+    is_entrypoint(item, APPROVE_ENTRYPOINT) && has_token_args(item, &[SPENDER_ARG_KEY])
+}
+
+// Every call on an existing token takes exactly one of its identifiers,
+// in addition to the `expected` arguments of the `Key` type.
+// There must be no other arguments.
+fn has_token_args(item: &ExecutableDeployItem, expected: &[&str]) -> bool {
+    let args = item.args();
+    let has_identifier = has_arg(args, TOKEN_ID_ARG_KEY, &CLType::U64)
+        ^ has_arg(args, TOKEN_HASH_ARG_KEY, &CLType::String);
+    args.len() == expected.len() + 1
+        && has_identifier
+        && expected
+            .iter()
+            .all(|name| has_arg(args, name, &CLType::Key))
+}
+
+fn parse_token_owner(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, TOKEN_OWNER_ARG_KEY, "owner", false, Ok)
+}
+
+fn parse_token_id(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    match parse_optional_arg(args, TOKEN_ID_ARG_KEY, "token id", false, Ok)? {
+        Some(element) => Ok(Some(element)),
+        None => parse_optional_arg(args, TOKEN_HASH_ARG_KEY, "token id", false, Ok),
+    }
+}
+
+// Hash of the metadata's UTF-8 bytes, so that it can be cross-checked with the wallet.
+fn parse_meta_data_hash(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    match args.get(TOKEN_META_DATA_ARG_KEY) {
+        Some(cl_value) => {
+            let meta_data: String =
+                cl_value
+                    .clone()
+                    .into_t()
+                    .map_err(|_| ParseError::InvalidArgType {
+                        name: TOKEN_META_DATA_ARG_KEY.to_string(),
+                        expected: CLType::String,
+                        found: cl_value.cl_type().clone(),
+                    })?;
+            let meta_data_hash = checksummed_hex::encode(Digest::hash(meta_data.as_bytes()));
+            Ok(Some(Element::expert("meta hash", meta_data_hash)))
+        }
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+
+use crate::ledger::Element;
+
+// Chain names longer than that are not used by any known network.
+const MAX_CHAIN_NAME_LEN: usize = 64;
+
+/// Names of the chains the Ledger app knows about.
+#[derive(Clone, Debug)]
+pub(crate) struct KnownChains {
+    mainnet: BTreeSet<String>,
+    testnet: BTreeSet<String>,
+}
+
+impl KnownChains {
+    pub(crate) fn new<I, J>(mainnet: I, testnet: J) -> Self
+    where
+        I: IntoIterator<Item = String>,
+        J: IntoIterator<Item = String>,
+    {
+        KnownChains {
+            mainnet: mainnet.into_iter().collect(),
+            testnet: testnet.into_iter().collect(),
+        }
+    }
+
+    fn is_known(&self, chain_name: &str) -> bool {
+        self.mainnet.contains(chain_name) || self.testnet.contains(chain_name)
+    }
+
+    /// Whether the transaction for `chain_name` is aimed at a test network.
+    ///
+    /// Unknown chains are treated as test networks, only known mainnet chains are not.
+    pub(crate) fn is_testnet(&self, chain_name: &str) -> bool {
+        !self.mainnet.contains(chain_name)
+    }
+}
+
+// Whether the chain name may be used to trick the user -
+// it's empty, too long to be reviewed or includes characters that look like others.
+fn is_suspicious(chain_name: &str) -> bool {
+    chain_name.is_empty()
+        || chain_name.len() > MAX_CHAIN_NAME_LEN
+        || !chain_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+/// Parses the chain name, followed by a warning when the chain is not one of the `known_chains`.
+pub(crate) fn parse_chain_name(chain_name: &str, known_chains: &KnownChains) -> Vec<Element> {
+    let mut elements = vec![Element::regular("chain ID", chain_name.to_string())];
+    if is_suspicious(chain_name) {
+        elements.push(Element::regular(
+            "warning",
+            "Suspicious chain ID".to_string(),
+        ));
+    } else if !known_chains.is_known(chain_name) {
+        elements.push(Element::regular("warning", "Unknown chain ID".to_string()));
+    }
+    elements
+}
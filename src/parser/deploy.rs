@@ -11,25 +11,27 @@ use casper_node::types::{Deploy, DeployHeader};
 use casper_types::{
     bytesrepr::Bytes,
     system::mint::{self, ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO},
-    CLValue, RuntimeArgs, U512,
+    CLValue, PublicKey, RuntimeArgs, U512,
 };
 use thousands::Separable;
 
 use super::{
-    auction::{
-        is_delegate, is_redelegate, is_undelegate, parse_delegation, parse_redelegation,
-        parse_undelegation,
-    },
+    chain::{parse_chain_name, KnownChains},
+    recognizer::Context,
     runtime_args::{parse_runtime_args, parse_transfer_args},
+    ParseError, ParserConfig,
 };
 
-pub(crate) fn parse_deploy_header(dh: &DeployHeader) -> Vec<Element> {
+pub(crate) fn parse_deploy_header(
+    dh: &DeployHeader,
+    known_chains: &KnownChains,
+) -> Result<Vec<Element>, ParseError> {
     let mut elements = vec![];
-    elements.push(Element::regular("chain ID", dh.chain_name().to_string()));
-    elements.push(Element::regular("account", parse_public_key(dh.account())));
+    elements.extend(parse_chain_name(dh.chain_name(), known_chains));
+    elements.push(Element::regular("account", parse_public_key(dh.account())?));
     elements.push(Element::expert(
         "timestamp",
-        timestamp_to_seconds_res(dh.timestamp()),
+        timestamp_to_seconds_res(dh.timestamp())?,
     ));
     elements.push(Element::expert("ttl", format!("{}", dh.ttl())));
     elements.push(Element::expert("gas price", format!("{}", dh.gas_price())));
@@ -37,77 +39,111 @@ pub(crate) fn parse_deploy_header(dh: &DeployHeader) -> Vec<Element> {
         "Deps #",
         format!("{:?}", dh.dependencies().len()),
     ));
-    elements
+    Ok(elements)
 }
 
-pub(crate) fn parse_phase(item: &ExecutableDeployItem, phase: TxnPhase) -> Vec<Element> {
-    if is_delegate(item) {
-        parse_delegation(item)
-    } else if is_undelegate(item) {
-        parse_undelegation(item)
-    } else if is_redelegate(item) {
-        parse_redelegation(item)
-    } else {
-        let mut elements: Vec<Element> = deploy_type(phase, item);
-        match item {
-            ExecutableDeployItem::ModuleBytes { module_bytes, args }
-                if is_system_payment(phase, module_bytes) =>
-            {
-                // The only required argument for the system payment is `amount`.
-                elements.extend(parse_fee(args).into_iter());
-                let args_sans_amount = remove_amount_arg(args.clone());
-                if !args_sans_amount.is_empty() {
-                    // If system payment had more args than the required `amount` then they should be parsed.
-                    elements.extend(parse_runtime_args(&phase, &args));
-                }
-            }
-            ExecutableDeployItem::ModuleBytes {
-                module_bytes: _,
-                args,
-            } => {
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
-            }
-            ExecutableDeployItem::StoredContractByHash {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
-            }
-            ExecutableDeployItem::StoredContractByName {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
-            }
-            ExecutableDeployItem::StoredVersionedContractByHash {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
-            }
-            ExecutableDeployItem::StoredVersionedContractByName {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
-            }
-            ExecutableDeployItem::Transfer { args } => {
-                elements.extend(parse_transfer_args(args));
-                let args_sans_transfer = remove_transfer_args(args.clone());
-                if !args_sans_transfer.is_empty() {
-                    println!("{:?}", args_sans_transfer);
-                    // If there are more arguments left that were not used, display digest of args.
-                    elements.extend(parse_runtime_args(&phase, &args));
-                }
+/// Parses the payment or session item, with the first of the `config`'s recognizers
+/// that recognizes it, or as a generic one.
+pub(crate) fn parse_phase(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    account: &PublicKey,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, ParseError> {
+    let mut elements: Vec<Element> = vec![];
+    if is_custom_payment(phase, item) {
+        // Custom payment code decides how much is paid, so there's no single fee to display.
+        elements.push(Element::regular("fee", "custom payment".to_string()));
+    }
+    let context = Context {
+        phase,
+        account,
+        args_config: &config.args,
+    };
+    match config.recognizers.recognize(item, &context)? {
+        Some(recognized) => elements.extend(recognized.elements),
+        None => elements.extend(parse_generic(item, &context)?),
+    }
+    Ok(elements)
+}
+
+fn parse_generic(
+    item: &ExecutableDeployItem,
+    context: &Context,
+) -> Result<Vec<Element>, ParseError> {
+    let phase = context.phase;
+    let args_config = context.args_config;
+    let mut elements: Vec<Element> = deploy_type(phase, item);
+    match item {
+        ExecutableDeployItem::ModuleBytes { module_bytes, args }
+            if is_system_payment(phase, module_bytes) =>
+        {
+            // The only required argument for the system payment is `amount`.
+            elements.extend(parse_fee(args)?.into_iter());
+            let args_sans_amount = remove_amount_arg(args.clone());
+            if !args_sans_amount.is_empty() {
+                // If system payment had more args than the required `amount` then they should be parsed.
+                elements.extend(parse_runtime_args(&phase, args, args_config)?);
             }
         }
-        elements
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: _,
+            args,
+        } => {
+            elements.extend(parse_phase_amount(phase, args)?);
+            elements.extend(parse_runtime_args(&phase, args, args_config)?);
+        }
+        ExecutableDeployItem::StoredContractByHash {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_phase_amount(phase, args)?);
+            elements.extend(parse_runtime_args(&phase, args, args_config)?);
+        }
+        ExecutableDeployItem::StoredContractByName {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_phase_amount(phase, args)?);
+            elements.extend(parse_runtime_args(&phase, args, args_config)?);
+        }
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_phase_amount(phase, args)?);
+            elements.extend(parse_runtime_args(&phase, args, args_config)?);
+        }
+        ExecutableDeployItem::StoredVersionedContractByName {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_phase_amount(phase, args)?);
+            elements.extend(parse_runtime_args(&phase, args, args_config)?);
+        }
+        ExecutableDeployItem::Transfer { args } => {
+            elements.extend(parse_native_transfer(args, context)?);
+        }
     }
+    Ok(elements)
+}
+
+/// Parses arguments of the native transfer.
+pub(crate) fn parse_native_transfer(
+    args: &RuntimeArgs,
+    context: &Context,
+) -> Result<Vec<Element>, ParseError> {
+    let mut elements = parse_transfer_args(args, context.account)?;
+    let args_sans_transfer = remove_transfer_args(args.clone());
+    if !args_sans_transfer.is_empty() {
+        // If there are more arguments left that were not used, display digest of args.
+        elements.extend(parse_runtime_args(
+            &context.phase,
+            args,
+            context.args_config,
+        )?);
+    }
+    Ok(elements)
 }
 
 /// Returns the main elements describing the deploy:
@@ -132,6 +168,11 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
                     Element::regular(&phase_label, "contract".to_string()),
                     // Cntrct hash: <hash of contract bytes>
                     Element::regular("Cntrct hash", contract_hash),
+                    // Cntrct size: <length of contract bytes>
+                    Element::expert(
+                        "Cntrct size",
+                        format!("{} bytes", module_bytes.inner_bytes().len()),
+                    ),
                 ]
             }
         }
@@ -193,6 +234,16 @@ fn is_system_payment(phase: TxnPhase, module_bytes: &Bytes) -> bool {
     phase.is_payment() && module_bytes.inner_bytes().is_empty()
 }
 
+// Payment that is anything but the system payment, i.e. a stored contract or payment Wasm.
+fn is_custom_payment(phase: TxnPhase, item: &ExecutableDeployItem) -> bool {
+    match item {
+        ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
+            phase.is_payment() && !is_system_payment(phase, module_bytes)
+        }
+        _ => phase.is_payment(),
+    }
+}
+
 fn remove_amount_arg(args: RuntimeArgs) -> RuntimeArgs {
     let mut tree: BTreeMap<String, CLValue> = args.into();
     tree.remove(mint::ARG_AMOUNT);
@@ -210,22 +261,47 @@ fn remove_transfer_args(args: RuntimeArgs) -> RuntimeArgs {
     tree.into()
 }
 
+// Number of motes in one CSPR is 10^MOTES_DECIMALS.
+const MOTES_DECIMALS: usize = 9;
+
+/// Formats amount of motes as CSPR, for example `CSPR 1 000.000000001`.
+///
+/// Amount is never rounded nor truncated, so every mote is visible - even for values up to `U512::MAX`.
+/// Whole CSPRs are separated with spaces, trailing zeros of the fractional part are dropped.
 fn format_amount(motes: U512) -> String {
-    format!("{} motes", motes.separate_with_spaces())
+    let motes_per_cspr = U512::exp10(MOTES_DECIMALS);
+    let (cspr, remainder) = motes.div_mod(motes_per_cspr);
+    let cspr = cspr.separate_with_spaces();
+    if remainder.is_zero() {
+        return format!("CSPR {}", cspr);
+    }
+    let fraction = format!("{:0>width$}", remainder.to_string(), width = MOTES_DECIMALS);
+    format!("CSPR {}.{}", cspr, fraction.trim_end_matches('0'))
 }
 
-pub(crate) fn parse_fee(args: &RuntimeArgs) -> Option<Element> {
+pub(crate) fn parse_fee(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
     parse_motes(args, "fee")
 }
 
-pub(crate) fn parse_amount(args: &RuntimeArgs) -> Option<Element> {
+pub(crate) fn parse_amount(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
     parse_motes(args, "amount")
 }
 
-fn parse_motes(args: &RuntimeArgs, ledger_label: &str) -> Option<Element> {
-    let f = |amount_str: String| {
-        let motes_amount = U512::from_dec_str(&amount_str).unwrap();
-        format_amount(motes_amount)
+// In the payment phase `amount` is what the custom payment code is asked to pay,
+// so it's labelled differently than the amount the session operates on.
+fn parse_phase_amount(phase: TxnPhase, args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    if phase.is_payment() {
+        parse_motes(args, "payment amt")
+    } else {
+        parse_amount(args)
+    }
+}
+
+fn parse_motes(args: &RuntimeArgs, ledger_label: &str) -> Result<Option<Element>, ParseError> {
+    let f = |amount_str: String| -> Result<String, ParseError> {
+        let motes_amount =
+            U512::from_dec_str(&amount_str).map_err(|_| ParseError::InvalidMotes(amount_str))?;
+        Ok(format_amount(motes_amount))
     };
     parse_optional_arg(args, mint::ARG_AMOUNT, ledger_label, false, f)
 }
@@ -238,31 +314,76 @@ mod amount {
 
     #[test]
     fn amount_space_separated() {
-        let one: U512 = 1u8.into();
-        let expected = "1 motes".to_string();
-        assert_eq!(expected, format_amount(one));
-        let thousand: U512 = 1_000u32.into();
-        let expected = "1 000 motes".to_string();
-        assert_eq!(expected, format_amount(thousand));
-        let ten_thousand: U512 = 10_000u64.into();
-        let expected = "10 000 motes".to_string();
-        assert_eq!(expected, format_amount(ten_thousand));
-        let ten_billion: U512 = U512::from(10000000000u64);
-        let expected = "10 000 000 000 motes".to_string();
-        assert_eq!(expected, format_amount(ten_billion));
+        let thousand_cspr: U512 = U512::from(1_000_000_000_000u64);
+        let expected = "CSPR 1 000".to_string();
+        assert_eq!(expected, format_amount(thousand_cspr));
+        let ten_billion_cspr: U512 = U512::from(10_000_000_000u64) * U512::exp10(9);
+        let expected = "CSPR 10 000 000 000".to_string();
+        assert_eq!(expected, format_amount(ten_billion_cspr));
+    }
+
+    #[test]
+    fn amount_as_cspr() {
+        let zero: U512 = U512::zero();
+        assert_eq!("CSPR 0".to_string(), format_amount(zero));
+        let one_mote: U512 = 1u8.into();
+        assert_eq!("CSPR 0.000000001".to_string(), format_amount(one_mote));
+        let almost_one_cspr: U512 = 999_999_999u32.into();
+        assert_eq!(
+            "CSPR 0.999999999".to_string(),
+            format_amount(almost_one_cspr)
+        );
+        let one_cspr: U512 = 1_000_000_000u32.into();
+        assert_eq!("CSPR 1".to_string(), format_amount(one_cspr));
+        let one_cspr_and_mote: U512 = 1_000_000_001u32.into();
+        assert_eq!(
+            "CSPR 1.000000001".to_string(),
+            format_amount(one_cspr_and_mote)
+        );
+        let cspr_and_half: U512 = U512::from(24_500_000_000u64);
+        assert_eq!("CSPR 24.5".to_string(), format_amount(cspr_and_half));
     }
-}
 
-pub(crate) fn identity<T>(el: T) -> T {
-    el
+    #[test]
+    fn max_amount_is_not_truncated() {
+        let formatted = format_amount(U512::MAX);
+        let digits: String = formatted.chars().filter(|c| c.is_ascii_digit()).collect();
+        assert_eq!(U512::MAX.to_string(), digits);
+        assert!(formatted.starts_with("CSPR 13 407 807"));
+        assert!(formatted.ends_with(".006084095"));
+    }
 }
 
-pub(crate) fn parse_approvals(d: &Deploy) -> Vec<Element> {
+/// Maximum number of signers that can be listed.
+/// Labels of the further ones (`signer 10000`) wouldn't fit into 11 characters of Ledger Nano S.
+pub(crate) const MAX_LISTED_SIGNERS: u64 = 9999;
+
+/// Parses the number of approvals, followed by the keys of (at most `max_signers`) signers.
+///
+/// Signer that is the deploy's account is flagged. When there are more signers than `max_signers`,
+/// the list ends with an element saying how many of them were not displayed.
+pub(crate) fn parse_approvals(d: &Deploy, max_signers: usize) -> Result<Vec<Element>, ParseError> {
     let approvals_count = d.approvals().len();
-    vec![Element::expert(
+    let mut elements = vec![Element::expert(
         "Approvals #",
         format!("{}", approvals_count),
-    )]
+    )];
+    for (idx, approval) in d.approvals().iter().take(max_signers).enumerate() {
+        let signer = parse_public_key(approval.signer())?;
+        let value = if approval.signer() == d.header().account() {
+            format!("{} (account)", signer)
+        } else {
+            signer
+        };
+        elements.push(Element::expert(&format!("signer {}", idx + 1), value));
+    }
+    if approvals_count > max_signers {
+        elements.push(Element::expert(
+            "signers",
+            format!("+{} more", approvals_count - max_signers),
+        ));
+    }
+    Ok(elements)
 }
 
 fn entrypoint(entry_point: &str) -> Element {
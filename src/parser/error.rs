@@ -0,0 +1,75 @@
+use std::fmt::{self, Display, Formatter};
+
+use casper_types::{bytesrepr, CLType, Timestamp};
+
+use crate::message::MessageError;
+
+/// Reasons why a transaction (or a message) can't be represented as Ledger elements.
+#[derive(Debug)]
+pub(crate) enum ParseError {
+    /// Native transfer was found where a contract call was expected.
+    UnexpectedTransfer { method: String },
+    /// Argument's type is different than the expected one.
+    InvalidArgType {
+        name: String,
+        expected: CLType,
+        found: CLType,
+    },
+    /// System public key does not represent any account.
+    SystemPublicKey,
+    /// Public key variant that was unknown at the time of writing.
+    UnsupportedPublicKey,
+    /// `CLValue` bytes could not be deserialized into its declared type.
+    FromBytes {
+        cl_type: CLType,
+        error: bytesrepr::Error,
+    },
+    /// Value could not be serialized.
+    ToBytes(bytesrepr::Error),
+    /// `CLValue` could not be turned into its JSON representation.
+    Json(String),
+    /// Amount is not a valid, decimal number of motes.
+    InvalidMotes(String),
+    /// Timestamp can't be represented as a system time.
+    InvalidTimestamp(Timestamp),
+    /// Message for signing breaks one of the rules.
+    InvalidMessage(MessageError),
+    /// Element's label does not fit into the device's label row.
+    LabelTooLong { label: String, max_len: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedTransfer { method } => {
+                write!(f, "unexpected native transfer for {}", method)
+            }
+            ParseError::InvalidArgType {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "argument {} should be {:?}, found {:?}",
+                name, expected, found
+            ),
+            ParseError::SystemPublicKey => write!(f, "unexpected system public key"),
+            ParseError::UnsupportedPublicKey => write!(f, "unsupported public key variant"),
+            ParseError::FromBytes { cl_type, error } => {
+                write!(f, "can't deserialize {:?} value: {:?}", cl_type, error)
+            }
+            ParseError::ToBytes(error) => write!(f, "can't serialize value: {:?}", error),
+            ParseError::Json(error) => write!(f, "can't represent value as JSON: {}", error),
+            ParseError::InvalidMotes(amount) => write!(f, "invalid amount of motes: {}", amount),
+            ParseError::InvalidTimestamp(timestamp) => {
+                write!(f, "timestamp out of range: {}", timestamp.millis())
+            }
+            ParseError::InvalidMessage(error) => write!(f, "invalid message: {}", error),
+            ParseError::LabelTooLong { label, max_len } => {
+                write!(f, "label {} is longer than {} characters", label, max_len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
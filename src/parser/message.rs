@@ -0,0 +1,29 @@
+use crate::{ledger::Element, message::CasperMessage};
+
+use super::ParseError;
+
+/// Parses the message into its text (if it can be displayed) and the hash.
+///
+/// Message has to follow the rules of `CasperMessage::parse`. Its text is displayed only
+/// if it has no control characters (other than new lines, which are displayed as `\n`).
+/// Otherwise the user can only review the hash of the message.
+pub(crate) fn parse_message(m: CasperMessage) -> Result<Vec<Element>, ParseError> {
+    let m = CasperMessage::parse(m.inner().to_vec()).map_err(ParseError::InvalidMessage)?;
+    let mut msg_hash = Element::regular("Msg hash", hex::encode(m.hashed()));
+    match m.body().and_then(message_text) {
+        Some(text) => {
+            msg_hash.as_expert();
+            Ok(vec![Element::regular("Message", text), msg_hash])
+        }
+        None => Ok(vec![msg_hash]),
+    }
+}
+
+// Returns the text of the message body, if it can be displayed on Ledger.
+fn message_text(body: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(body).ok()?;
+    if text.chars().any(|c| c.is_control() && c != '\n') {
+        return None;
+    }
+    Some(text.replace('\n', "\\n"))
+}
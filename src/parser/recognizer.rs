@@ -0,0 +1,227 @@
+//! Display rules for the calls to known contracts.
+//!
+//! Every `Recognizer` decides whether it knows how to display an `ExecutableDeployItem`
+//! and, if it does, turns it into Ledger elements. `Recognizers` is an ordered registry of them -
+//! the first one that recognizes the item wins, items that none of them recognizes are displayed
+//! as generic contract executions. Built-in auction, native transfer, CEP-18 and CEP-78 rules
+//! are registered by `Recognizers::builtin`, more can be added with `Recognizers::register`.
+
+use std::fmt::{self, Debug, Formatter};
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::PublicKey;
+
+use crate::ledger::{Element, TxnPhase};
+
+use super::{
+    auction, cep18, cep78, deploy::parse_native_transfer, runtime_args::ArgsConfig, ParseError,
+};
+
+/// Result of recognizing an `ExecutableDeployItem`.
+pub(crate) struct Recognized {
+    /// Label displayed as the transaction's **Type**.
+    /// When `None`, the transaction is displayed as a generic contract execution.
+    pub(crate) type_label: Option<String>,
+    /// Elements describing the item.
+    pub(crate) elements: Vec<Element>,
+}
+
+/// What is known about the deploy, besides the item being recognized.
+pub(crate) struct Context<'a> {
+    /// Whether the item is the payment or the session.
+    pub(crate) phase: TxnPhase,
+    /// Account the deploy is sent from.
+    pub(crate) account: &'a PublicKey,
+    pub(crate) args_config: &'a ArgsConfig,
+}
+
+/// Rule for displaying calls to a known contract.
+pub(crate) trait Recognizer {
+    /// Name of the rule, for debugging.
+    fn name(&self) -> &str;
+
+    /// Returns `None` when the `item` is not recognized by this rule.
+    fn recognize(
+        &self,
+        item: &ExecutableDeployItem,
+        context: &Context,
+    ) -> Result<Option<Recognized>, ParseError>;
+}
+
+type Detector = fn(&ExecutableDeployItem) -> bool;
+type ItemParser = fn(&ExecutableDeployItem, TxnPhase) -> Result<Vec<Element>, ParseError>;
+
+/// Recognizer of a contract call that is detected and parsed by plain functions.
+pub(crate) struct ContractCall {
+    type_label: &'static str,
+    detector: Detector,
+    parser: ItemParser,
+}
+
+impl ContractCall {
+    pub(crate) fn new(type_label: &'static str, detector: Detector, parser: ItemParser) -> Self {
+        ContractCall {
+            type_label,
+            detector,
+            parser,
+        }
+    }
+}
+
+impl Recognizer for ContractCall {
+    fn name(&self) -> &str {
+        self.type_label
+    }
+
+    fn recognize(
+        &self,
+        item: &ExecutableDeployItem,
+        context: &Context,
+    ) -> Result<Option<Recognized>, ParseError> {
+        if !(self.detector)(item) {
+            return Ok(None);
+        }
+        Ok(Some(Recognized {
+            type_label: Some(self.type_label.to_string()),
+            elements: (self.parser)(item, context.phase)?,
+        }))
+    }
+}
+
+/// Recognizer of the native (CSPR) transfers.
+struct NativeTransfer;
+
+impl Recognizer for NativeTransfer {
+    fn name(&self) -> &str {
+        "Token transfer"
+    }
+
+    fn recognize(
+        &self,
+        item: &ExecutableDeployItem,
+        context: &Context,
+    ) -> Result<Option<Recognized>, ParseError> {
+        match item {
+            ExecutableDeployItem::Transfer { args } => Ok(Some(Recognized {
+                type_label: Some(self.name().to_string()),
+                elements: parse_native_transfer(args, context)?,
+            })),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Ordered registry of `Recognizer`s.
+pub(crate) struct Recognizers {
+    recognizers: Vec<Box<dyn Recognizer>>,
+}
+
+impl Recognizers {
+    /// Returns a registry with no recognizers - every item is displayed as a generic one.
+    pub(crate) fn empty() -> Self {
+        Recognizers {
+            recognizers: vec![],
+        }
+    }
+
+    /// Returns a registry with all the built-in recognizers.
+    pub(crate) fn builtin() -> Self {
+        let mut recognizers = Recognizers::empty();
+        recognizers
+            .register(ContractCall::new(
+                "Delegate",
+                auction::is_delegate,
+                auction::parse_delegation,
+            ))
+            .register(ContractCall::new(
+                "Undelegate",
+                auction::is_undelegate,
+                auction::parse_undelegation,
+            ))
+            .register(ContractCall::new(
+                "Redelegate",
+                auction::is_redelegate,
+                auction::parse_redelegation,
+            ))
+            .register(ContractCall::new(
+                "Add bid",
+                auction::is_add_bid,
+                auction::parse_add_bid,
+            ))
+            .register(ContractCall::new(
+                "Withdraw bid",
+                auction::is_withdraw_bid,
+                auction::parse_withdraw_bid,
+            ))
+            .register(ContractCall::new(
+                "Activate bid",
+                auction::is_activate_bid,
+                auction::parse_activate_bid,
+            ))
+            .register(ContractCall::new(
+                "Token transfer (CEP-18)",
+                cep18::is_cep18_transfer,
+                cep18::parse_cep18_transfer,
+            ))
+            .register(ContractCall::new(
+                "Token approval (CEP-18)",
+                cep18::is_cep18_approve,
+                cep18::parse_cep18_approve,
+            ))
+            .register(ContractCall::new(
+                "Token transfer (CEP-18)",
+                cep18::is_cep18_transfer_from,
+                cep18::parse_cep18_transfer_from,
+            ))
+            .register(ContractCall::new(
+                "NFT mint (CEP-78)",
+                cep78::is_cep78_mint,
+                cep78::parse_cep78_mint,
+            ))
+            .register(ContractCall::new(
+                "NFT transfer (CEP-78)",
+                cep78::is_cep78_transfer,
+                cep78::parse_cep78_transfer,
+            ))
+            .register(ContractCall::new(
+                "NFT burn (CEP-78)",
+                cep78::is_cep78_burn,
+                cep78::parse_cep78_burn,
+            ))
+            .register(ContractCall::new(
+                "NFT approval (CEP-78)",
+                cep78::is_cep78_approve,
+                cep78::parse_cep78_approve,
+            ))
+            .register(NativeTransfer);
+        recognizers
+    }
+
+    /// Adds the `recognizer`, after all the already registered ones.
+    pub(crate) fn register<R: Recognizer + 'static>(&mut self, recognizer: R) -> &mut Self {
+        self.recognizers.push(Box::new(recognizer));
+        self
+    }
+
+    /// Returns the result of the first recognizer that recognizes the `item`.
+    pub(crate) fn recognize(
+        &self,
+        item: &ExecutableDeployItem,
+        context: &Context,
+    ) -> Result<Option<Recognized>, ParseError> {
+        for recognizer in &self.recognizers {
+            if let Some(recognized) = recognizer.recognize(item, context)? {
+                return Ok(Some(recognized));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Debug for Recognizers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.recognizers.iter().map(|recognizer| recognizer.name()))
+            .finish()
+    }
+}
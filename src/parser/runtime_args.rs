@@ -1,63 +1,143 @@
+use crate::checksummed_hex;
 use crate::ledger::{Element, TxnPhase};
 use crate::utils::cl_value_to_string;
-use casper_types::bytesrepr::ToBytes;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{FromBytes, ToBytes};
 use casper_types::system::mint::{ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO};
-use casper_types::RuntimeArgs;
+use casper_types::{CLType, CLTyped, CLValue, Key, PublicKey, RuntimeArgs, URef};
 
-use super::deploy::{identity, parse_amount};
+use super::{deploy::parse_amount, ParseError};
 
-/// Parses all contract arguments into a form:
+/// How arguments of a contract call are presented on Ledger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ArgsMode {
+    /// Only the hash of all the arguments.
+    Hash,
+    /// Hash of all the arguments in the regular mode, followed by every argument in the expert mode.
+    ExpertList,
+    /// Every argument, in both regular and expert modes.
+    FullList,
+}
+
+/// Maximum number of arguments that can be listed.
+/// Labels of the further ones (`arg-100-name`) wouldn't fit into 11 characters of Ledger Nano S.
+pub(crate) const MAX_LISTED_ARGS: u64 = 100;
+
+/// Configuration of the contract arguments' presentation.
+///
+/// Arguments are listed only when there are at most `max_count` of them
+/// and none of their values is longer than `max_value_len` characters.
+/// Otherwise Ledger falls back to displaying the hash of the arguments.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ArgsConfig {
+    pub(crate) mode: ArgsMode,
+    pub(crate) max_count: usize,
+    pub(crate) max_value_len: usize,
+}
+
+impl ArgsConfig {
+    pub(crate) fn new(mode: ArgsMode, max_count: usize, max_value_len: usize) -> Self {
+        ArgsConfig {
+            mode,
+            max_count,
+            max_value_len,
+        }
+    }
+}
+
+/// Parses all contract arguments.
+///
+/// Depending on the `config`, the arguments are represented either as their hash:
+/// args hash: <phase>-<hash>
+/// or as a list, in a form:
 /// arg-n-name: <name>
 /// arg-n-val: <val>
 /// where n is the ordinal number of the argument.
-pub(crate) fn parse_runtime_args(phase: &TxnPhase, ra: &RuntimeArgs) -> Vec<Element> {
-    let mut elements: Vec<Element> = vec![];
-    if !ra.is_empty() {
-        let args_digest =
-            casper_hashing::Digest::hash(ToBytes::to_bytes(ra).expect("ToBytes to work."));
-        let args_hash = base16::encode_lower(&args_digest);
-        elements.push(Element::regular(
-            "args hash",
-            format!("{}-{}", phase.to_string().to_lowercase(), args_hash),
-        ));
+pub(crate) fn parse_runtime_args(
+    phase: &TxnPhase,
+    ra: &RuntimeArgs,
+    config: &ArgsConfig,
+) -> Result<Vec<Element>, ParseError> {
+    if ra.is_empty() {
+        return Ok(vec![]);
     }
 
-    // NOTE: The code that follows would iterate over all args and parse them
-    // for Ledger presentation in a following format:
-    // Arg-n-name: <name>
-    // Arg-n-val: <value>
-    // But this could lead to very long confirmation screens in Ledger,
-    // so we opted for shorter form above: display just hash of the runtime args.
-    // If we ever decide to bring back the more elaborate version, this code would do it.
-    // let named_args: BTreeMap<String, CLValue> = ra.clone().into();
-    // for (idx, (name, value)) in named_args.iter().enumerate() {
-    //     let name_label = format!("arg-{}-name", idx);
-    //     elements.push(Element::expert(&name_label, name.to_string()));
-    //     let value_label = format!("arg-{}-val", idx);
-    //     let value_str = cl_value_to_string(value);
-    //     elements.push(Element::expert(&value_label, value_str));
-    // }
-    elements
+    let args_list = match config.mode {
+        ArgsMode::Hash => None,
+        ArgsMode::ExpertList => parse_args_list(ra, config, true)?,
+        ArgsMode::FullList => parse_args_list(ra, config, false)?,
+    };
+
+    match args_list {
+        // Hash is still displayed in the regular mode, so that it can be cross-checked with the wallet.
+        Some(args_list) if config.mode == ArgsMode::ExpertList => {
+            let mut elements = vec![parse_args_hash(phase, ra)?];
+            elements.extend(args_list);
+            Ok(elements)
+        }
+        Some(args_list) => Ok(args_list),
+        None => Ok(vec![parse_args_hash(phase, ra)?]),
+    }
+}
+
+fn parse_args_hash(phase: &TxnPhase, ra: &RuntimeArgs) -> Result<Element, ParseError> {
+    let args_digest =
+        casper_hashing::Digest::hash(ToBytes::to_bytes(ra).map_err(ParseError::ToBytes)?);
+    let args_hash = base16::encode_lower(&args_digest);
+    Ok(Element::regular(
+        "args hash",
+        format!("{}-{}", phase.to_string().to_lowercase(), args_hash),
+    ))
 }
 
-pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
+// Returns `None` when the arguments exceed the limits from `config`
+// and should be represented by their hash instead.
+fn parse_args_list(
+    ra: &RuntimeArgs,
+    config: &ArgsConfig,
+    expert: bool,
+) -> Result<Option<Vec<Element>>, ParseError> {
+    if ra.len() > config.max_count {
+        return Ok(None);
+    }
+
+    let mut elements = vec![];
+    for (idx, named_arg) in ra.named_args().enumerate() {
+        let value = cl_value_to_string(named_arg.cl_value())?;
+        if value.chars().count() > config.max_value_len {
+            return Ok(None);
+        }
+        let name_label = format!("arg-{}-name", idx);
+        let value_label = format!("arg-{}-val", idx);
+        if expert {
+            elements.push(Element::expert(&name_label, named_arg.name().to_string()));
+            elements.push(Element::expert(&value_label, value));
+        } else {
+            elements.push(Element::regular(&name_label, named_arg.name().to_string()));
+            elements.push(Element::regular(&value_label, value));
+        }
+    }
+    Ok(Some(elements))
+}
+
+pub(crate) fn parse_optional_arg<F: Fn(String) -> Result<String, ParseError>>(
     args: &RuntimeArgs,
     key: &str,
     label: &str,
     expert: bool,
     f: F,
-) -> Option<Element> {
+) -> Result<Option<Element>, ParseError> {
     match args.get(key) {
         Some(cl_value) => {
-            let value = f(cl_value_to_string(cl_value));
+            let value = f(cl_value_to_string(cl_value)?)?;
             let element = if expert {
                 Element::expert(label, value)
             } else {
                 Element::regular(label, value)
             };
-            Some(element)
+            Ok(Some(element))
         }
-        None => None,
+        None => Ok(None),
     }
 }
 
@@ -67,15 +147,177 @@ pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
 /// * ID
 /// Optional fields:
 /// * source
-pub(crate) fn parse_transfer_args(args: &RuntimeArgs) -> Vec<Element> {
-    let mut elements: Vec<Element> = parse_optional_arg(args, ARG_TO, "recipient", false, identity)
+///
+/// The target is followed by its account hash (in the expert mode) when it's an account,
+/// and by a note when it's the `account` sending the transfer, or a purse.
+pub(crate) fn parse_transfer_args(
+    args: &RuntimeArgs,
+    account: &PublicKey,
+) -> Result<Vec<Element>, ParseError> {
+    let mut elements: Vec<Element> = parse_optional_arg(args, ARG_TO, "recipient", false, Ok)?
         .into_iter()
         .collect();
-    elements.extend(parse_optional_arg(args, ARG_SOURCE, "from", true, identity).into_iter());
-    elements.extend(parse_optional_arg(
-        args, ARG_TARGET, "target", false, identity,
-    ));
-    elements.extend(parse_amount(args).into_iter());
-    elements.extend(parse_optional_arg(args, ARG_ID, "ID", true, identity).into_iter());
-    elements
+    elements.extend(parse_optional_arg(args, ARG_SOURCE, "from", true, Ok)?.into_iter());
+    elements.extend(parse_optional_arg(args, ARG_TARGET, "target", false, Ok)?);
+    elements.extend(parse_target_account_hash(args)?);
+    elements.extend(parse_target_note(args, account)?);
+    elements.extend(parse_amount(args)?.into_iter());
+    elements.extend(parse_optional_arg(args, ARG_ID, "ID", true, Ok)?.into_iter());
+    Ok(elements)
+}
+
+/// What the transfer's target is, as far as it can be told from the deploy alone.
+#[derive(Debug, PartialEq, Eq)]
+enum TargetKind {
+    /// Account that sends the transfer.
+    OwnAccount,
+    /// Purse the tokens can be written to.
+    Purse,
+    Other,
+}
+
+// Account hash of the target, whichever encoding of the account it is. `None` for non-accounts.
+fn target_account_hash(target: &CLValue) -> Result<Option<AccountHash>, ParseError> {
+    let account_hash = match target.cl_type() {
+        CLType::ByteArray(32) => Some(AccountHash::new(into_arg(ARG_TARGET, target)?)),
+        CLType::PublicKey => {
+            let public_key: PublicKey = into_arg(ARG_TARGET, target)?;
+            Some(public_key.to_account_hash())
+        }
+        CLType::Key => match into_arg(ARG_TARGET, target)? {
+            Key::Account(account_hash) => Some(account_hash),
+            _ => None,
+        },
+        _ => None,
+    };
+    Ok(account_hash)
+}
+
+fn target_kind(target: &CLValue, account: &PublicKey) -> Result<TargetKind, ParseError> {
+    if let Some(account_hash) = target_account_hash(target)? {
+        if account_hash == account.to_account_hash() {
+            return Ok(TargetKind::OwnAccount);
+        }
+        return Ok(TargetKind::Other);
+    }
+    let uref: Option<URef> = match target.cl_type() {
+        CLType::URef => Some(into_arg(ARG_TARGET, target)?),
+        CLType::Key => match into_arg(ARG_TARGET, target)? {
+            Key::URef(uref) => Some(uref),
+            _ => None,
+        },
+        _ => None,
+    };
+    match uref {
+        Some(uref) if uref.is_writeable() => Ok(TargetKind::Purse),
+        _ => Ok(TargetKind::Other),
+    }
+}
+
+// The same account can be given as a public key, account hash bytes or `Key::Account`,
+// each displayed differently. Its account hash is displayed the same way for all of them.
+fn parse_target_account_hash(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    let target = match args.get(ARG_TARGET) {
+        Some(target) => target,
+        None => return Ok(None),
+    };
+    Ok(target_account_hash(target)?.map(|account_hash| {
+        Element::expert("acct hash", checksummed_hex::encode(account_hash.value()))
+    }))
+}
+
+fn parse_target_note(
+    args: &RuntimeArgs,
+    account: &PublicKey,
+) -> Result<Option<Element>, ParseError> {
+    let target = match args.get(ARG_TARGET) {
+        Some(target) => target,
+        None => return Ok(None),
+    };
+    let note = match target_kind(target, account)? {
+        TargetKind::OwnAccount => "Self transfer",
+        TargetKind::Purse => "Target is a purse",
+        TargetKind::Other => return Ok(None),
+    };
+    Ok(Some(Element::regular("note", note.to_string())))
+}
+
+fn into_arg<T: CLTyped + FromBytes>(name: &str, cl_value: &CLValue) -> Result<T, ParseError> {
+    cl_value
+        .clone()
+        .into_t()
+        .map_err(|_| ParseError::InvalidArgType {
+            name: name.to_string(),
+            expected: T::cl_type(),
+            found: cl_value.cl_type().clone(),
+        })
+}
+
+#[cfg(test)]
+mod target_kind {
+    use casper_types::{
+        account::AccountHash, AccessRights, AsymmetricType, CLValue, Key, PublicKey, URef,
+    };
+
+    use super::{target_account_hash, target_kind, TargetKind};
+
+    fn account() -> PublicKey {
+        PublicKey::ed25519_from_bytes([1u8; 32]).unwrap()
+    }
+
+    fn kind(target: CLValue) -> TargetKind {
+        target_kind(&target, &account()).unwrap()
+    }
+
+    #[test]
+    fn own_account_in_every_encoding() {
+        let account_hash = account().to_account_hash();
+        assert_eq!(
+            kind(CLValue::from_t(account_hash.value()).unwrap()),
+            TargetKind::OwnAccount
+        );
+        assert_eq!(
+            kind(CLValue::from_t(Key::Account(account_hash)).unwrap()),
+            TargetKind::OwnAccount
+        );
+        assert_eq!(
+            kind(CLValue::from_t(account()).unwrap()),
+            TargetKind::OwnAccount
+        );
+        let other = PublicKey::ed25519_from_bytes([2u8; 32]).unwrap();
+        assert_eq!(kind(CLValue::from_t(other).unwrap()), TargetKind::Other);
+        assert_eq!(
+            kind(CLValue::from_t(AccountHash::new([2u8; 32]).value()).unwrap()),
+            TargetKind::Other
+        );
+    }
+
+    #[test]
+    fn account_hash_is_the_same_for_every_encoding() {
+        let account_hash = account().to_account_hash();
+        for target in [
+            CLValue::from_t(account()).unwrap(),
+            CLValue::from_t(account_hash.value()).unwrap(),
+            CLValue::from_t(Key::Account(account_hash)).unwrap(),
+        ] {
+            assert_eq!(target_account_hash(&target).unwrap(), Some(account_hash));
+        }
+        let purse = URef::new([3u8; 32], AccessRights::READ_ADD_WRITE);
+        assert_eq!(
+            target_account_hash(&CLValue::from_t(purse).unwrap()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn purse_needs_write_rights() {
+        let purse = URef::new([3u8; 32], AccessRights::READ_ADD_WRITE);
+        let read_only = URef::new([3u8; 32], AccessRights::READ);
+        assert_eq!(kind(CLValue::from_t(purse).unwrap()), TargetKind::Purse);
+        assert_eq!(
+            kind(CLValue::from_t(Key::URef(purse)).unwrap()),
+            TargetKind::Purse
+        );
+        assert_eq!(kind(CLValue::from_t(read_only).unwrap()), TargetKind::Other);
+    }
 }
@@ -1,14 +1,73 @@
-use casper_types::Timestamp;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, RuntimeArgs, Timestamp};
 use std::time::{Duration, SystemTime};
 
+use crate::ledger::{Element, TxnPhase};
+
+use super::{deploy::deploy_type, ParseError};
+
 // Ledger/Zondax supports timestamps only up to seconds resolution.
 // `Display` impl for the `Timestamp` in the casper-node crate uses milliseconds-resolution
 // so we need a custom implementation for the timestamp representation.
-pub(crate) fn timestamp_to_seconds_res(timestamp: Timestamp) -> String {
+pub(crate) fn timestamp_to_seconds_res(timestamp: Timestamp) -> Result<String, ParseError> {
     let system_time = SystemTime::UNIX_EPOCH
         .checked_add(Duration::from_millis(timestamp.millis()))
-        .expect("should be within system time limits");
-    format!("{}", humantime::format_rfc3339_seconds(system_time))
+        .ok_or(ParseError::InvalidTimestamp(timestamp))?;
+    Ok(format!(
+        "{}",
+        humantime::format_rfc3339_seconds(system_time)
+    ))
+}
+
+/// Parses a call to a (non-system) stored contract, made in the `phase`, with the `args_parser`.
+///
+/// The contract is displayed in the regular mode - user has to see which contract is called.
+pub(crate) fn parse_contract_call<F>(
+    method: &str,
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    args_parser: F,
+) -> Result<Vec<Element>, ParseError>
+where
+    F: Fn(&RuntimeArgs) -> Result<Vec<Element>, ParseError>,
+{
+    let mut elements = deploy_type(phase, item);
+    match item {
+        ExecutableDeployItem::Transfer { .. } => {
+            return Err(ParseError::UnexpectedTransfer {
+                method: method.to_string(),
+            })
+        }
+        ExecutableDeployItem::StoredContractByHash { args, .. }
+        | ExecutableDeployItem::StoredContractByName { args, .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
+        | ExecutableDeployItem::ModuleBytes { args, .. } => {
+            elements.extend(args_parser(args)?);
+        }
+    };
+    Ok(elements)
+}
+
+/// Returns `true` when the stored contract's entry point is *literally* `expected`.
+/// `ModuleBytes` have no entry points.
+pub(crate) fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
+    match item {
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => false,
+        ExecutableDeployItem::StoredContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredContractByName { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. } => {
+            entry_point == expected
+        }
+    }
+}
+
+/// Returns `true` when there's the `name` argument of `cl_type` in `args`.
+pub(crate) fn has_arg(args: &RuntimeArgs, name: &str, cl_type: &CLType) -> bool {
+    args.get(name)
+        .map(|cl_value| cl_value.cl_type() == cl_type)
+        .unwrap_or(false)
 }
 
 #[cfg(test)]
@@ -0,0 +1,112 @@
+//! Self-check of the generated test vectors.
+//!
+//! Every test vector's `blob` is decoded back into the `Deploy` (or `CasperMessage`) and rendered
+//! again. Both the bytes and the pages have to be exactly the same as in the test vector,
+//! otherwise the `blob` doesn't describe the transaction that was rendered - which would happen,
+//! for example, if the serialization format changed with the casper-node dependency.
+
+use std::fmt::{self, Display, Formatter};
+
+use casper_node::types::Deploy;
+use casper_types::bytesrepr::{self, ToBytes};
+
+use crate::{
+    ledger::{self, LimitedLedgerConfig, ZondaxRepr},
+    message::CasperMessage,
+    parser::ParseError,
+    verification,
+};
+
+/// Reasons why the test vector doesn't survive the round trip.
+#[derive(Debug)]
+pub(crate) enum RoundTripError {
+    /// `blob` is not a valid hex string.
+    InvalidHex(hex::FromHexError),
+    /// `blob` can't be deserialized.
+    FromBytes(bytesrepr::Error),
+    /// Deserialized value can't be serialized back.
+    ToBytes(bytesrepr::Error),
+    /// Deserialized value serializes to different bytes than the `blob`.
+    BytesMismatch,
+    /// Deserialized `Deploy` is aimed at a different kind of network.
+    TestnetMismatch,
+    /// Deserialized value could be represented on Ledger while the original one couldn't, or vice versa.
+    ParseMismatch,
+    /// Deserialized `Deploy` is rejected for a different reason than the original one, or isn't rejected at all.
+    VerificationMismatch,
+    /// Deserialized value is represented with different pages.
+    PagesMismatch { expert: bool },
+}
+
+impl Display for RoundTripError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundTripError::InvalidHex(error) => write!(f, "blob is not a valid hex: {}", error),
+            RoundTripError::FromBytes(error) => write!(f, "can't deserialize blob: {}", error),
+            RoundTripError::ToBytes(error) => write!(f, "can't serialize blob: {}", error),
+            RoundTripError::BytesMismatch => write!(f, "re-serialized blob differs"),
+            RoundTripError::TestnetMismatch => write!(f, "testnet flag differs"),
+            RoundTripError::ParseMismatch => write!(f, "parsing result differs"),
+            RoundTripError::VerificationMismatch => write!(f, "verification result differs"),
+            RoundTripError::PagesMismatch { expert: false } => write!(f, "regular pages differ"),
+            RoundTripError::PagesMismatch { expert: true } => write!(f, "expert pages differ"),
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Checks that the `Deploy` decoded from the test vector's `blob` is the one it was built from.
+pub(crate) fn check_deploy(
+    vector: &ZondaxRepr,
+    config: &LimitedLedgerConfig,
+) -> Result<(), RoundTripError> {
+    let bytes = hex::decode(vector.blob()).map_err(RoundTripError::InvalidHex)?;
+    let deploy: Deploy =
+        bytesrepr::deserialize(bytes.clone()).map_err(RoundTripError::FromBytes)?;
+    if deploy.to_bytes().map_err(RoundTripError::ToBytes)? != bytes {
+        return Err(RoundTripError::BytesMismatch);
+    }
+    if ledger::is_testnet(&deploy, config) != vector.testnet() {
+        return Err(RoundTripError::TestnetMismatch);
+    }
+    let rejection = verification::verify_deploy(&deploy)
+        .err()
+        .map(|error| error.to_string());
+    check_pages(vector, ledger::deploy_pages(deploy, config), rejection)
+}
+
+/// Checks that the `CasperMessage` decoded from the test vector's `blob` is the one it was built from.
+pub(crate) fn check_message(
+    vector: &ZondaxRepr,
+    config: &LimitedLedgerConfig,
+) -> Result<(), RoundTripError> {
+    let bytes = hex::decode(vector.blob()).map_err(RoundTripError::InvalidHex)?;
+    // Messages are signed as they are, there's no serialization format to decode.
+    let message = CasperMessage::raw(bytes);
+    check_pages(vector, ledger::message_pages(message, config), None)
+}
+
+// `rejection` is the reason why a node would reject the transaction, if it would.
+fn check_pages(
+    vector: &ZondaxRepr,
+    pages: Result<(Vec<String>, Vec<String>), ParseError>,
+    rejection: Option<String>,
+) -> Result<(), RoundTripError> {
+    match pages {
+        Ok((output, output_expert)) => {
+            if vector.invalid_reason() != rejection.as_deref() {
+                return Err(RoundTripError::VerificationMismatch);
+            }
+            if output != vector.output() {
+                return Err(RoundTripError::PagesMismatch { expert: false });
+            }
+            if output_expert != vector.output_expert() {
+                return Err(RoundTripError::PagesMismatch { expert: true });
+            }
+            Ok(())
+        }
+        Err(error) if vector.invalid_reason() == Some(error.to_string().as_str()) => Ok(()),
+        Err(_) => Err(RoundTripError::ParseMismatch),
+    }
+}
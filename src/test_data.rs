@@ -8,18 +8,29 @@ use casper_types::{
 };
 use rand::{prelude::*, Rng};
 
-use auction::{delegate, undelegate};
+use auction::{activate_bid, add_bid, delegate, undelegate, withdraw_bid};
 
 use crate::sample::Sample;
 
 use self::{auction::redelegate, commons::UREF_ADDR};
 
+mod amounts;
 mod auction;
+mod cep18;
+mod cep78;
+mod chain_name;
+mod checksum;
 mod commons;
 mod generic;
+mod malformed;
 mod native_transfer;
+mod page_limit;
+mod recipient;
+mod self_transfer;
 pub(crate) mod sign_message;
 mod system_payment;
+mod tampered;
+mod wasm;
 
 // From the chainspec.
 // 1 minute.
@@ -37,6 +48,11 @@ const MAX_DEPS_COUNT: u8 = 10;
 const MIN_APPROVALS_COUNT: u8 = 1;
 const MAX_APPROVALS_COUNT: u8 = 10;
 
+// Chain name of the samples outside of the `chain_name` family - the Casper mainnet,
+// so that they are displayed without the unknown chain warning.
+// Unknown chain names are covered only by the `chain_name` samples.
+const DEFAULT_CHAIN_NAME: &str = "casper";
+
 /// Represents native transfer sample.
 #[derive(Clone, Debug)]
 struct NativeTransfer {
@@ -179,6 +195,7 @@ fn make_deploy_sample(
     payment: Sample<ExecutableDeployItem>,
     ttl: TimeDiff,
     dependencies: Vec<DeployHash>,
+    chain_name: &str,
     signing_keys: &[SecretKey],
 ) -> Sample<Deploy> {
     let (main_key, secondary_keys) = signing_keys.split_at(1);
@@ -190,7 +207,7 @@ fn make_deploy_sample(
         ttl,
         2,
         dependencies,
-        String::from("mainnet"),
+        chain_name.to_string(),
         payment,
         session,
         &main_key[0],
@@ -266,8 +283,14 @@ fn construct_samples<R: Rng>(
             ttls.shuffle(rng);
             let ttl = ttls.first().cloned().unwrap();
 
-            let sample_deploy =
-                make_deploy_sample(session.clone(), payment.clone(), ttl, dependencies, &keys);
+            let sample_deploy = make_deploy_sample(
+                session.clone(),
+                payment.clone(),
+                ttl,
+                dependencies,
+                DEFAULT_CHAIN_NAME,
+                &keys,
+            );
             samples.push(sample_deploy);
         }
     }
@@ -306,6 +329,110 @@ pub(crate) fn generic_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     samples
 }
 
+/// Returns generic transactions with arguments around the limits of their presentation as a list.
+pub(crate) fn generic_args_samples<R: Rng>(
+    rng: &mut R,
+    max_count: usize,
+    max_value_len: usize,
+) -> Vec<Sample<Deploy>> {
+    construct_samples(
+        rng,
+        generic::args_limits(max_count, max_value_len),
+        vec![system_payment::valid()],
+    )
+}
+
+/// Returns samples that span one page less, exactly as many and one page more than `page_limit`
+/// in the regular mode, as counted by `page_count`.
+///
+/// Panics when any of these page counts can't be reached.
+pub(crate) fn page_limit_samples<F>(page_limit: u8, page_count: F) -> Vec<Sample<Deploy>>
+where
+    F: Fn(&Deploy) -> usize,
+{
+    page_limit::valid(page_limit, page_count)
+}
+
+/// Returns samples for various, both known and unknown, chain names.
+pub(crate) fn chain_name_samples() -> Vec<Sample<Deploy>> {
+    chain_name::valid()
+}
+
+/// Returns native transfers to the signer's own account, and to purses.
+pub(crate) fn self_transfer_samples() -> Vec<Sample<Deploy>> {
+    self_transfer::valid()
+}
+
+/// Returns native transfers to the same recipients, with the target encoded in every possible way.
+pub(crate) fn recipient_samples() -> Vec<Sample<Deploy>> {
+    recipient::valid()
+}
+
+/// Returns samples with amounts and fees around the boundaries of their CSPR representation.
+pub(crate) fn amount_samples() -> Vec<Sample<Deploy>> {
+    amounts::valid()
+}
+
+/// Returns sample inputs for the checksummed hex encoding.
+pub(crate) fn checksum_samples() -> Vec<Sample<Vec<u8>>> {
+    checksum::valid()
+}
+
+/// Returns samples with custom Wasm session code.
+pub(crate) fn wasm_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    construct_samples(rng, wasm::valid(), vec![system_payment::valid()])
+}
+
+/// Returns native transfers paid for by stored contracts and payment Wasm.
+pub(crate) fn custom_payment_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let transfer = NativeTransfer::new(
+        TransferTarget::bytes(),
+        U512::from(100000000),
+        0,
+        TransferSource::none(),
+    );
+    let session = Sample::new(
+        "native_transfer",
+        ExecutableDeployItem::Transfer {
+            args: transfer.into(),
+        },
+        true,
+    );
+    construct_samples(rng, vec![session], system_payment::custom())
+}
+
+/// Returns CEP-18 token transfers, approvals and calls that only resemble them.
+pub(crate) fn cep18_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut samples = construct_samples(rng, cep18::valid(), vec![system_payment::valid()]);
+    samples.extend(construct_samples(
+        rng,
+        cep18::invalid(),
+        vec![system_payment::valid()],
+    ));
+    samples
+}
+
+/// Returns CEP-78 NFT mints, transfers, burns, approvals and calls that only resemble them.
+pub(crate) fn cep78_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut samples = construct_samples(rng, cep78::valid(), vec![system_payment::valid()]);
+    samples.extend(construct_samples(
+        rng,
+        cep78::invalid(),
+        vec![system_payment::valid()],
+    ));
+    samples
+}
+
+/// Returns samples with hashes or approvals that don't match the deploy.
+pub(crate) fn tampered_samples() -> Vec<Sample<Deploy>> {
+    tampered::invalid()
+}
+
+/// Returns samples that can't be represented on Ledger.
+pub(crate) fn malformed_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    construct_samples(rng, malformed::invalid(), vec![system_payment::valid()])
+}
+
 pub(crate) fn native_transfer_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let mut native_transfer_samples =
         construct_samples(rng, native_transfer::valid(), vec![system_payment::valid()]);
@@ -343,3 +470,42 @@ pub(crate) fn undelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
 
     undelegate_samples
 }
+
+pub(crate) fn add_bid_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut add_bid_samples =
+        construct_samples(rng, add_bid::valid(), vec![system_payment::valid()]);
+
+    add_bid_samples.extend(construct_samples(
+        rng,
+        add_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    add_bid_samples
+}
+
+pub(crate) fn withdraw_bid_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut withdraw_bid_samples =
+        construct_samples(rng, withdraw_bid::valid(), vec![system_payment::valid()]);
+
+    withdraw_bid_samples.extend(construct_samples(
+        rng,
+        withdraw_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    withdraw_bid_samples
+}
+
+pub(crate) fn activate_bid_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut activate_bid_samples =
+        construct_samples(rng, activate_bid::valid(), vec![system_payment::valid()]);
+
+    activate_bid_samples.extend(construct_samples(
+        rng,
+        activate_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    activate_bid_samples
+}
@@ -0,0 +1,94 @@
+//! Sample test vectors for amounts and fees around the boundaries of their CSPR representation.
+//!
+//! Covers amounts with and without the fractional part, with the fractional part
+//! at its shortest and longest, right below and above the whole CSPR
+//! and the thousands separator, and the extremes of `U512`.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{bytesrepr::Bytes, runtime_args, RuntimeArgs, U512};
+
+use crate::sample::Sample;
+
+use super::{
+    make_deploy_sample, random_keys, system_payment, NativeTransfer, TransferSource,
+    TransferTarget, DEFAULT_CHAIN_NAME, MIN_TTL,
+};
+
+fn sample_motes() -> Vec<(&'static str, U512)> {
+    let motes_per_cspr = U512::from(1_000_000_000u64);
+    vec![
+        ("zero", U512::zero()),
+        ("one_mote", U512::one()),
+        ("tenth_cspr", motes_per_cspr / 10),
+        ("below_one_cspr", motes_per_cspr - 1),
+        ("one_cspr", motes_per_cspr),
+        ("above_one_cspr", motes_per_cspr + 1),
+        ("fractional_cspr", U512::from(24_500_000_000u64)),
+        ("below_thousand_cspr", motes_per_cspr * 1000 - 1),
+        ("thousand_cspr", motes_per_cspr * 1000),
+        ("below_max", U512::MAX - 1),
+        ("max", U512::MAX),
+    ]
+}
+
+fn native_transfer(amount: U512) -> Sample<ExecutableDeployItem> {
+    let transfer = NativeTransfer::new(TransferTarget::bytes(), amount, 0, TransferSource::none());
+    Sample::new(
+        "native_transfer",
+        ExecutableDeployItem::Transfer {
+            args: transfer.into(),
+        },
+        true,
+    )
+}
+
+fn payment_with_fee(fee: U512) -> Sample<ExecutableDeployItem> {
+    let payment = ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: runtime_args! {
+            "amount" => fee
+        },
+    };
+    Sample::new("payment_system", payment, true)
+}
+
+fn make_sample(
+    label: String,
+    session: Sample<ExecutableDeployItem>,
+    payment: Sample<ExecutableDeployItem>,
+) -> Sample<Deploy> {
+    let (session_label, session, valid) = session.destructure();
+    let session = Sample::new(format!("{}__{}", label, session_label), session, valid);
+    make_deploy_sample(
+        session,
+        payment,
+        MIN_TTL,
+        vec![],
+        DEFAULT_CHAIN_NAME,
+        &random_keys(1),
+    )
+}
+
+/// Returns valid native transfers, with amounts and fees around the boundaries.
+pub(super) fn valid() -> Vec<Sample<Deploy>> {
+    let default_amount = U512::from(1_000_000_000u64);
+
+    let amounts = sample_motes().into_iter().map(|(label, amount)| {
+        make_sample(
+            format!("amount_{}", label),
+            native_transfer(amount),
+            system_payment::valid(),
+        )
+    });
+
+    let fees = sample_motes().into_iter().map(|(label, fee)| {
+        make_sample(
+            format!("fee_{}", label),
+            native_transfer(default_amount),
+            payment_with_fee(fee),
+        )
+    });
+
+    amounts.chain(fees).collect()
+}
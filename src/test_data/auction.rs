@@ -1,4 +1,7 @@
+pub mod activate_bid;
+pub mod add_bid;
 pub(crate) mod commons;
 pub mod delegate;
 pub mod redelegate;
 pub mod undelegate;
+pub mod withdraw_bid;
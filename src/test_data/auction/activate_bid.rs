@@ -0,0 +1,81 @@
+//! Sample test vectors for activate bid deploys.
+//!
+//! Method name (entrypoint):
+//! `activate_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `validator_public_key` | `PublicKey` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons;
+use crate::test_data::commons::invalid_contract_calls;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs};
+
+const ENTRY_POINT_NAME: &str = "activate_bid";
+
+fn sample_activate_bids() -> Vec<RuntimeArgs> {
+    let ed25519_validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let secp256k1_validator: PublicKey = PublicKey::secp256k1_from_bytes(
+        hex::decode(b"026e1b7a8e3243f5ff14e825b0fde15103588bb61e6ae99084968b017118e0504f").unwrap(),
+    )
+    .unwrap();
+
+    vec![ed25519_validator, secp256k1_validator]
+        .into_iter()
+        .map(|validator| {
+            runtime_args! {
+                "validator_public_key" => validator,
+            }
+        })
+        .collect()
+}
+
+fn invalid_activate_bid() -> Vec<Sample<ExecutableDeployItem>> {
+    let validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+
+    let valid_args = runtime_args! {
+        "validator_public_key" => validator.clone(),
+    };
+
+    // `public_key` is the argument name used by the other bid entry points.
+    // Without the expected argument, or with an argument of unexpected type,
+    // it's a generic transaction, which is still valid.
+    // System key is recognized as the validator, but it does not represent any,
+    // so the transaction can't be represented on Ledger.
+    let invalid_args_samples = vec![
+        Sample::new(
+            "missing_validator_public_key",
+            runtime_args! {
+                "public_key" => validator,
+            },
+            true,
+        ),
+        Sample::new(
+            "invalid_type_validator_public_key",
+            runtime_args! {
+                "validator_public_key" => "validator",
+            },
+            true,
+        ),
+        Sample::new(
+            "system_public_key",
+            runtime_args! {
+                "validator_public_key" => PublicKey::system(),
+            },
+            false,
+        ),
+    ];
+
+    invalid_contract_calls(ENTRY_POINT_NAME, valid_args, invalid_args_samples)
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    commons::valid(ENTRY_POINT_NAME, sample_activate_bids())
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_activate_bid()
+}
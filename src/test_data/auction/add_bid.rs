@@ -0,0 +1,139 @@
+//! Sample test vectors for add bid deploys.
+//!
+//! Method name (entrypoint):
+//! `add_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `public_key` | `PublicKey` |
+//! | `delegation_rate` | `u8` |
+//! | `amount` | `U512` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons;
+use crate::test_data::commons::invalid_contract_calls;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs, U512};
+
+const ENTRY_POINT_NAME: &str = "add_bid";
+
+// Delegation rate is a percentage, anything above that is rejected by the auction contract.
+const MAX_DELEGATION_RATE: u8 = 100;
+
+#[derive(Clone, Debug)]
+struct AddBid {
+    public_key: PublicKey,
+    delegation_rate: u8,
+    amount: U512,
+}
+
+impl AddBid {
+    fn new(public_key: PublicKey, delegation_rate: u8, amount: U512) -> Self {
+        AddBid {
+            public_key,
+            delegation_rate,
+            amount,
+        }
+    }
+}
+
+impl From<AddBid> for RuntimeArgs {
+    fn from(d: AddBid) -> Self {
+        let mut ra = RuntimeArgs::new();
+        ra.insert("public_key", d.public_key).unwrap();
+        ra.insert("delegation_rate", d.delegation_rate).unwrap();
+        ra.insert("amount", d.amount).unwrap();
+        ra
+    }
+}
+
+// Creates vector of sample `AddBid` objects,
+// covering edge cases of both the `delegation_rate` and the `amount`.
+fn sample_add_bids() -> Vec<AddBid> {
+    let amount_min = U512::from(0u8);
+    let amount_mid = U512::from(100000000);
+    let amount_max = U512::MAX;
+
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+
+    vec![
+        (0, amount_min),
+        (10, amount_mid),
+        (MAX_DELEGATION_RATE, amount_max),
+    ]
+    .into_iter()
+    .map(|(delegation_rate, amount)| AddBid::new(public_key.clone(), delegation_rate, amount))
+    .collect()
+}
+
+fn invalid_add_bid() -> Vec<Sample<ExecutableDeployItem>> {
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let amount = U512::from(100000000u64);
+    let delegation_rate = 10u8;
+
+    let valid_args = runtime_args! {
+        "public_key" => public_key.clone(),
+        "delegation_rate" => delegation_rate,
+        "amount" => amount,
+    };
+
+    let invalid_args_samples = {
+        let missing_required_public_key = runtime_args! {
+            "delegation_rate" => delegation_rate,
+            "amount" => amount,
+        };
+
+        let missing_required_delegation_rate = runtime_args! {
+            "public_key" => public_key.clone(),
+            "amount" => amount,
+        };
+
+        let missing_required_amount = runtime_args! {
+            "public_key" => public_key.clone(),
+            "delegation_rate" => delegation_rate,
+        };
+
+        let invalid_type_delegation_rate = runtime_args! {
+            "public_key" => public_key.clone(),
+            "delegation_rate" => "abc",
+            "amount" => amount,
+        };
+
+        let delegation_rate_too_high = runtime_args! {
+            "public_key" => public_key,
+            "delegation_rate" => MAX_DELEGATION_RATE + 1,
+            "amount" => amount,
+        };
+
+        // Missing arguments, or arguments of unexpected types, make it a generic transaction, which is still valid.
+        // Delegation rate above 100% is recognized as add bid but will be rejected by the auction contract.
+        vec![
+            Sample::new("missing_public_key", missing_required_public_key, true),
+            Sample::new(
+                "missing_delegation_rate",
+                missing_required_delegation_rate,
+                true,
+            ),
+            Sample::new("missing_amount", missing_required_amount, true),
+            Sample::new(
+                "invalid_type_delegation_rate",
+                invalid_type_delegation_rate,
+                true,
+            ),
+            Sample::new("delegation_rate_too_high", delegation_rate_too_high, false),
+        ]
+    };
+
+    invalid_contract_calls(ENTRY_POINT_NAME, valid_args, invalid_args_samples)
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let add_bid_rargs = sample_add_bids().into_iter().map(Into::into).collect();
+
+    commons::valid(ENTRY_POINT_NAME, add_bid_rargs)
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_add_bid()
+}
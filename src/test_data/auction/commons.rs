@@ -51,8 +51,8 @@ pub(crate) fn invalid_delegation(entry_point: &str) -> Vec<Sample<ExecutableDepl
         };
 
         let invalid_amount_type = runtime_args! {
-            "validator" => validator,
-            "delegator" => delegator,
+            "validator" => validator.clone(),
+            "delegator" => delegator.clone(),
             "amount" => 100000u32
         };
 
@@ -73,6 +73,7 @@ pub(crate) fn invalid_delegation(entry_point: &str) -> Vec<Sample<ExecutableDepl
             let (label, ra, valid) = sample_ra.destructure();
             sample_executables(entry_point, ra, Some(label), valid)
         })
+        .chain(vec![auction_arg_not_string(delegator, validator, amount)])
         .chain(sample_executables(
             "invalid",
             valid_args.clone(),
@@ -82,3 +83,20 @@ pub(crate) fn invalid_delegation(entry_point: &str) -> Vec<Sample<ExecutableDepl
         .map(|sample| prepend_label(sample, entry_point))
         .collect()
 }
+
+// `auction` argument that doesn't name the auction entry point (it's not even a `String`)
+// makes the session a generic one - dApps may use an argument with that name for their own purposes.
+fn auction_arg_not_string(
+    delegator: PublicKey,
+    validator: PublicKey,
+    amount: U512,
+) -> Sample<ExecutableDeployItem> {
+    let args = runtime_args! {
+        "auction" => 1u8,
+        "delegator" => delegator,
+        "validator" => validator,
+        "amount" => amount,
+    };
+    let (_label, item, valid) = sample_module_bytes(args).destructure();
+    Sample::new("auction_arg_not_string", item, valid)
+}
@@ -0,0 +1,105 @@
+//! Sample test vectors for withdraw bid deploys.
+//!
+//! Method name (entrypoint):
+//! `withdraw_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `public_key` | `PublicKey` |
+//! | `amount` | `U512` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons;
+use crate::test_data::commons::invalid_contract_calls;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs, U512};
+
+const ENTRY_POINT_NAME: &str = "withdraw_bid";
+
+#[derive(Clone, Debug)]
+struct WithdrawBid {
+    public_key: PublicKey,
+    amount: U512,
+}
+
+impl WithdrawBid {
+    fn new(public_key: PublicKey, amount: U512) -> Self {
+        WithdrawBid { public_key, amount }
+    }
+}
+
+impl From<WithdrawBid> for RuntimeArgs {
+    fn from(d: WithdrawBid) -> Self {
+        let mut ra = RuntimeArgs::new();
+        ra.insert("public_key", d.public_key).unwrap();
+        ra.insert("amount", d.amount).unwrap();
+        ra
+    }
+}
+
+fn sample_withdraw_bids() -> Vec<WithdrawBid> {
+    let amount_min = U512::from(0u8);
+    let amount_mid = U512::from(100000000);
+    let amount_max = U512::MAX;
+    let amounts = vec![amount_min, amount_mid, amount_max];
+
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+
+    amounts
+        .into_iter()
+        .map(|amount| WithdrawBid::new(public_key.clone(), amount))
+        .collect()
+}
+
+fn invalid_withdraw_bid() -> Vec<Sample<ExecutableDeployItem>> {
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let amount = U512::from(100000000u64);
+
+    let valid_args = runtime_args! {
+        "public_key" => public_key.clone(),
+        "amount" => amount,
+    };
+
+    let invalid_args_samples = {
+        let missing_required_public_key = runtime_args! {
+            "amount" => amount,
+        };
+
+        let missing_required_amount = runtime_args! {
+            "public_key" => public_key.clone(),
+        };
+
+        let invalid_type_amount = runtime_args! {
+            "public_key" => public_key,
+            "amount" => 100000000u64,
+        };
+
+        let system_public_key = runtime_args! {
+            "public_key" => PublicKey::system(),
+            "amount" => amount,
+        };
+
+        // Missing arguments, or arguments of unexpected types, make it a generic transaction, which is still valid.
+        // System key is recognized as the bidder, but it does not represent any validator,
+        // so the transaction can't be represented on Ledger.
+        vec![
+            Sample::new("missing_public_key", missing_required_public_key, true),
+            Sample::new("missing_amount", missing_required_amount, true),
+            Sample::new("invalid_type_amount", invalid_type_amount, true),
+            Sample::new("system_public_key", system_public_key, false),
+        ]
+    };
+
+    invalid_contract_calls(ENTRY_POINT_NAME, valid_args, invalid_args_samples)
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let withdraw_bid_rargs = sample_withdraw_bids().into_iter().map(Into::into).collect();
+
+    commons::valid(ENTRY_POINT_NAME, withdraw_bid_rargs)
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_withdraw_bid()
+}
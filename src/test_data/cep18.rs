@@ -0,0 +1,192 @@
+//! Sample test vectors for CEP-18 (fungible token) contract calls.
+//!
+//! Method names (entrypoints) and arguments:
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `transfer` | `recipient: Key`, `amount: U256` |
+//! | `approve` | `spender: Key`, `amount: U256` |
+//! | `transfer_from` | `owner: Key`, `recipient: Key`, `amount: U256` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    account::{AccountHash, ACCOUNT_HASH_LENGTH},
+    runtime_args, AsymmetricType, Key, PublicKey, RuntimeArgs, U256, U512,
+};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{invalid_contract_calls, prepend_label, sample_executables},
+};
+
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const APPROVE_ENTRYPOINT: &str = "approve";
+const TRANSFER_FROM_ENTRYPOINT: &str = "transfer_from";
+
+fn account_key() -> Key {
+    Key::Account(AccountHash::new([1u8; ACCOUNT_HASH_LENGTH]))
+}
+
+fn contract_key() -> Key {
+    Key::Hash([2u8; 32])
+}
+
+// Tokens can be sent both to accounts and to contracts.
+fn sample_transfers() -> Vec<RuntimeArgs> {
+    vec![
+        runtime_args! {
+            "recipient" => account_key(),
+            "amount" => U256::zero(),
+        },
+        runtime_args! {
+            "recipient" => contract_key(),
+            "amount" => U256::from(100000000u64),
+        },
+        runtime_args! {
+            "recipient" => account_key(),
+            "amount" => U256::MAX,
+        },
+    ]
+}
+
+fn sample_approvals() -> Vec<RuntimeArgs> {
+    vec![
+        runtime_args! {
+            "spender" => contract_key(),
+            "amount" => U256::from(100000000u64),
+        },
+        runtime_args! {
+            "spender" => account_key(),
+            "amount" => U256::MAX,
+        },
+    ]
+}
+
+fn sample_transfers_from() -> Vec<RuntimeArgs> {
+    vec![
+        runtime_args! {
+            "owner" => account_key(),
+            "recipient" => contract_key(),
+            "amount" => U256::from(100000000u64),
+        },
+        runtime_args! {
+            "owner" => account_key(),
+            "recipient" => account_key(),
+            "amount" => U256::MAX,
+        },
+    ]
+}
+
+// Calls with arguments that don't match the standard are generic contract executions
+// that any token-like contract could accept, so they are still valid.
+fn invalid_transfer() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "recipient" => account_key(),
+        "amount" => U256::from(100000000u64),
+    };
+
+    let invalid_args = vec![
+        Sample::new(
+            "missing_recipient",
+            runtime_args! {
+                "amount" => U256::from(100000000u64),
+            },
+            true,
+        ),
+        Sample::new(
+            "missing_amount",
+            runtime_args! {
+                "recipient" => account_key(),
+            },
+            true,
+        ),
+        Sample::new(
+            "invalid_type_amount",
+            runtime_args! {
+                "recipient" => account_key(),
+                "amount" => U512::from(100000000u64),
+            },
+            true,
+        ),
+        Sample::new(
+            "invalid_type_recipient",
+            runtime_args! {
+                "recipient" => PublicKey::ed25519_from_bytes([1u8; 32]).unwrap(),
+                "amount" => U256::from(100000000u64),
+            },
+            true,
+        ),
+        Sample::new(
+            "extra_arg",
+            runtime_args! {
+                "recipient" => account_key(),
+                "amount" => U256::from(100000000u64),
+                "memo" => "unexpected",
+            },
+            true,
+        ),
+    ];
+
+    invalid_contract_calls(TRANSFER_ENTRYPOINT, valid_args, invalid_args)
+}
+
+fn invalid_approve() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "spender" => contract_key(),
+        "amount" => U256::from(100000000u64),
+    };
+
+    let invalid_args = vec![Sample::new(
+        "missing_spender",
+        runtime_args! {
+            "amount" => U256::from(100000000u64),
+        },
+        true,
+    )];
+
+    invalid_contract_calls(APPROVE_ENTRYPOINT, valid_args, invalid_args)
+}
+
+fn invalid_transfer_from() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "owner" => account_key(),
+        "recipient" => contract_key(),
+        "amount" => U256::from(100000000u64),
+    };
+
+    let invalid_args = vec![Sample::new(
+        "missing_owner",
+        runtime_args! {
+            "recipient" => contract_key(),
+            "amount" => U256::from(100000000u64),
+        },
+        true,
+    )];
+
+    invalid_contract_calls(TRANSFER_FROM_ENTRYPOINT, valid_args, invalid_args)
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    vec![
+        (TRANSFER_ENTRYPOINT, sample_transfers()),
+        (APPROVE_ENTRYPOINT, sample_approvals()),
+        (TRANSFER_FROM_ENTRYPOINT, sample_transfers_from()),
+    ]
+    .into_iter()
+    .flat_map(|(entrypoint, args)| {
+        args.into_iter().flat_map(move |ra| {
+            sample_executables(entrypoint, ra, None, true)
+                .into_iter()
+                .map(move |sample| prepend_label(sample, &format!("cep18__{}", entrypoint)))
+        })
+    })
+    .collect()
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_transfer()
+        .into_iter()
+        .chain(invalid_approve())
+        .chain(invalid_transfer_from())
+        .map(|sample| prepend_label(sample, "cep18"))
+        .collect()
+}
@@ -0,0 +1,188 @@
+//! Sample test vectors for CEP-78 (NFT) contract calls.
+//!
+//! Method names (entrypoints) and arguments, where `<id>` is either `token_id: u64`
+//! or `token_hash: String`:
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `mint` | `token_owner: Key`, `token_meta_data: String`, optional `token_hash: String` |
+//! | `transfer` | `<id>`, `source_key: Key`, `target_key: Key` |
+//! | `burn` | `<id>` |
+//! | `approve` | `<id>`, `spender: Key` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    account::{AccountHash, ACCOUNT_HASH_LENGTH},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{invalid_contract_calls, prepend_label, sample_executables},
+};
+
+const MINT_ENTRYPOINT: &str = "mint";
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const BURN_ENTRYPOINT: &str = "burn";
+const APPROVE_ENTRYPOINT: &str = "approve";
+
+const TOKEN_HASH: &str = "a4f1a5fb0e6aeaa2cd7a67c4a7a2d0a3e2d1b3f9d3d8e0c4b0c0f9b0a4e1d2c3";
+
+const META_DATA: &str = r#"{"name":"Casper NFT","token_uri":"https://example.com/nft/1","checksum":"940bffb3f2bba35f84313aa26da09ece3ad47045c6a1292c2bbd2df4ab1a55fb"}"#;
+
+fn account_key(byte: u8) -> Key {
+    Key::Account(AccountHash::new([byte; ACCOUNT_HASH_LENGTH]))
+}
+
+fn contract_key() -> Key {
+    Key::Hash([2u8; 32])
+}
+
+// Adds the token identifier, in each of the identifier modes, to `args`.
+fn with_identifiers(args: RuntimeArgs) -> Vec<RuntimeArgs> {
+    let mut by_id = args.clone();
+    by_id.insert("token_id", u64::MAX).unwrap();
+    let mut by_hash = args;
+    by_hash.insert("token_hash", TOKEN_HASH).unwrap();
+    vec![by_id, by_hash]
+}
+
+fn sample_mints() -> Vec<RuntimeArgs> {
+    let by_ordinal = runtime_args! {
+        "token_owner" => account_key(1),
+        "token_meta_data" => META_DATA,
+    };
+    let by_hash = runtime_args! {
+        "token_owner" => contract_key(),
+        "token_meta_data" => META_DATA,
+        "token_hash" => TOKEN_HASH,
+    };
+    let empty_meta_data = runtime_args! {
+        "token_owner" => account_key(1),
+        "token_meta_data" => "",
+    };
+    vec![by_ordinal, by_hash, empty_meta_data]
+}
+
+fn sample_transfers() -> Vec<RuntimeArgs> {
+    with_identifiers(runtime_args! {
+        "source_key" => account_key(1),
+        "target_key" => account_key(3),
+    })
+}
+
+fn sample_burns() -> Vec<RuntimeArgs> {
+    with_identifiers(RuntimeArgs::new())
+}
+
+fn sample_approvals() -> Vec<RuntimeArgs> {
+    with_identifiers(runtime_args! {
+        "spender" => contract_key(),
+    })
+}
+
+// Calls with arguments that don't match the standard are generic contract executions
+// that any NFT-like contract could accept, so they are still valid.
+fn invalid_mint() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "token_owner" => account_key(1),
+        "token_meta_data" => META_DATA,
+    };
+
+    let invalid_args = vec![
+        Sample::new(
+            "missing_token_owner",
+            runtime_args! {
+                "token_meta_data" => META_DATA,
+            },
+            true,
+        ),
+        Sample::new(
+            "invalid_type_token_hash",
+            runtime_args! {
+                "token_owner" => account_key(1),
+                "token_meta_data" => META_DATA,
+                "token_hash" => 1u64,
+            },
+            true,
+        ),
+    ];
+
+    invalid_contract_calls(MINT_ENTRYPOINT, valid_args, invalid_args)
+}
+
+fn invalid_transfer() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "token_id" => 1u64,
+        "source_key" => account_key(1),
+        "target_key" => account_key(3),
+    };
+
+    let invalid_args = vec![
+        Sample::new(
+            "missing_target_key",
+            runtime_args! {
+                "token_id" => 1u64,
+                "source_key" => account_key(1),
+            },
+            true,
+        ),
+        Sample::new(
+            "both_identifiers",
+            runtime_args! {
+                "token_id" => 1u64,
+                "token_hash" => TOKEN_HASH,
+                "source_key" => account_key(1),
+                "target_key" => account_key(3),
+            },
+            true,
+        ),
+        Sample::new(
+            "invalid_type_token_id",
+            runtime_args! {
+                "token_id" => U256::one(),
+                "source_key" => account_key(1),
+                "target_key" => account_key(3),
+            },
+            true,
+        ),
+    ];
+
+    invalid_contract_calls(TRANSFER_ENTRYPOINT, valid_args, invalid_args)
+}
+
+fn invalid_burn() -> Vec<Sample<ExecutableDeployItem>> {
+    let valid_args = runtime_args! {
+        "token_id" => 1u64,
+    };
+
+    let invalid_args = vec![Sample::new("missing_token_id", RuntimeArgs::new(), true)];
+
+    invalid_contract_calls(BURN_ENTRYPOINT, valid_args, invalid_args)
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    vec![
+        (MINT_ENTRYPOINT, sample_mints()),
+        (TRANSFER_ENTRYPOINT, sample_transfers()),
+        (BURN_ENTRYPOINT, sample_burns()),
+        (APPROVE_ENTRYPOINT, sample_approvals()),
+    ]
+    .into_iter()
+    .flat_map(|(entrypoint, args)| {
+        args.into_iter().flat_map(move |ra| {
+            sample_executables(entrypoint, ra, None, true)
+                .into_iter()
+                .map(move |sample| prepend_label(sample, &format!("cep78__{}", entrypoint)))
+        })
+    })
+    .collect()
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_mint()
+        .into_iter()
+        .chain(invalid_transfer())
+        .chain(invalid_burn())
+        .map(|sample| prepend_label(sample, "cep78"))
+        .collect()
+}
@@ -0,0 +1,59 @@
+//! Sample test vectors for transactions aimed at various chains.
+//!
+//! Besides the known mainnet and testnet chains, it covers names that the Ledger user
+//! should be warned about: unknown ones, empty, very long and ones with non-ASCII characters
+//! that look like ASCII ones.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::U512;
+
+use crate::sample::Sample;
+
+use super::{
+    make_deploy_sample, random_keys, system_payment, NativeTransfer, TransferSource,
+    TransferTarget, MIN_TTL,
+};
+
+fn sample_chain_names() -> Vec<(&'static str, String)> {
+    vec![
+        ("mainnet", "casper".to_string()),
+        ("testnet", "casper-test".to_string()),
+        ("unknown", "casper-net-1".to_string()),
+        ("empty", String::new()),
+        ("long", "casper-test-".repeat(10)),
+        // Cyrillic 'с' and 'а'.
+        ("non_ascii", "\u{0441}\u{0430}sper".to_string()),
+        ("trailing_space", "casper ".to_string()),
+    ]
+}
+
+/// Returns valid native transfers, each for a different chain.
+///
+/// Chain name does not make a transaction invalid, the node that receives it
+/// is the one that rejects transactions for other chains.
+pub(super) fn valid() -> Vec<Sample<Deploy>> {
+    let amount = U512::from(100000000u64);
+    sample_chain_names()
+        .into_iter()
+        .map(|(label, chain_name)| {
+            let transfer =
+                NativeTransfer::new(TransferTarget::bytes(), amount, 0, TransferSource::none());
+            let session = Sample::new(
+                format!("chain_name_{}__native_transfer", label),
+                ExecutableDeployItem::Transfer {
+                    args: transfer.into(),
+                },
+                true,
+            );
+            make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MIN_TTL,
+                vec![],
+                &chain_name,
+                &random_keys(1),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+//! Sample inputs for the checksummed hex encoding.
+//!
+//! Covers the lengths of the data that is checksummed on Ledger - hashes and public keys -
+//! and the lengths around `SMALL_BYTES_COUNT`, above which the checksum is not applied.
+
+use casper_types::{blake2b, bytesrepr::ToBytes, AsymmetricType, PublicKey};
+
+use crate::{checksummed_hex::SMALL_BYTES_COUNT, sample::Sample};
+
+fn pattern(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i * 37 + 11) as u8).collect()
+}
+
+fn public_key_bytes(public_key: PublicKey) -> Vec<u8> {
+    // `ToBytes` representation is prefixed with the key's tag.
+    public_key.to_bytes().expect("public key should serialize")
+}
+
+pub(super) fn valid() -> Vec<Sample<Vec<u8>>> {
+    let ed25519_keys = vec![[1u8; 32], [0xffu8; 32]]
+        .into_iter()
+        .map(|bytes| PublicKey::ed25519_from_bytes(bytes).unwrap());
+    let secp256k1_keys = vec![
+        "026e1b7a8e3243f5ff14e825b0fde15103588bb61e6ae99084968b017118e0504f",
+        "0202531fe6068134503d2723133227c867ac8fa6c83c537e9a44c3c5bdbdcb1fe3",
+    ]
+    .into_iter()
+    .map(|key| PublicKey::secp256k1_from_bytes(hex::decode(key).unwrap()).unwrap());
+
+    let mut samples = vec![
+        Sample::new("hash_zeros", vec![0u8; 32], true),
+        Sample::new("hash_ones", vec![1u8; 32], true),
+        Sample::new("hash_ff", vec![0xffu8; 32], true),
+        Sample::new("hash_blake2b", blake2b(b"casper").to_vec(), true),
+    ];
+    samples.extend(
+        ed25519_keys.map(|key| Sample::new("public_key_ed25519", public_key_bytes(key), true)),
+    );
+    samples.extend(
+        secp256k1_keys.map(|key| Sample::new("public_key_secp256k1", public_key_bytes(key), true)),
+    );
+    samples.extend(vec![
+        Sample::new(
+            "below_small_bytes_count",
+            pattern(SMALL_BYTES_COUNT - 1),
+            true,
+        ),
+        Sample::new("at_small_bytes_count", pattern(SMALL_BYTES_COUNT), true),
+        Sample::new(
+            "above_small_bytes_count",
+            pattern(SMALL_BYTES_COUNT + 1),
+            true,
+        ),
+    ]);
+    samples
+}
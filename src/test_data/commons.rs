@@ -92,6 +92,29 @@ pub(crate) fn prepend_label(
     Sample::new(label, executable, valid)
 }
 
+/// Constructs transactions that call `entry_point` with each of the `invalid_args`,
+/// and transactions that call an invalid entry point with `valid_args`.
+pub(crate) fn invalid_contract_calls(
+    entry_point: &str,
+    valid_args: RuntimeArgs,
+    invalid_args: Vec<Sample<RuntimeArgs>>,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    invalid_args
+        .into_iter()
+        .flat_map(|sample_ra| {
+            let (label, ra, valid) = sample_ra.destructure();
+            sample_executables(entry_point, ra, Some(label), valid)
+        })
+        .chain(sample_executables(
+            "invalid",
+            valid_args,
+            Some("invalid_entrypoint".to_string()),
+            true, // Generic transaction (not the one of a known contract) may use similar set of arguments with a different entrypoint. It MUSTN'T be invalid b/c it would get rejected by the Ledger.
+        ))
+        .map(|sample| prepend_label(sample, entry_point))
+        .collect()
+}
+
 pub(crate) const UREF_ADDR: [u8; UREF_ADDR_LENGTH] = [
     74, 207, 207, 108, 104, 76, 88, 202, 246, 179, 41, 110, 58, 151, 196, 160, 74, 250, 247, 123,
     184, 117, 202, 154, 64, 164, 93, 178, 84, 233, 74, 117,
@@ -34,6 +34,43 @@ pub(crate) fn valid<R: Rng>(rng: &mut R) -> Vec<Sample<ExecutableDeployItem>> {
     output
 }
 
+/// Returns valid generic transactions with arguments right within and just above
+/// the limits of arguments' presentation as a list (`max_count` and `max_value_len`).
+pub(crate) fn args_limits(
+    max_count: usize,
+    max_value_len: usize,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    const ENTRYPOINT: &str = "generic-txn-entrypoint";
+
+    let string_args = |count: usize, value_len: usize| -> RuntimeArgs {
+        let mut ra = RuntimeArgs::new();
+        for idx in 0..count {
+            ra.insert(format!("arg-{}", idx), "a".repeat(value_len))
+                .unwrap();
+        }
+        ra
+    };
+
+    // At least one argument is needed to test the length of the value.
+    let value_args_count = max_count.max(1);
+
+    vec![
+        ("args_count_at_limit", string_args(max_count, 1)),
+        ("args_count_above_limit", string_args(max_count + 1, 1)),
+        (
+            "args_value_at_limit",
+            string_args(value_args_count, max_value_len),
+        ),
+        (
+            "args_value_above_limit",
+            string_args(value_args_count, max_value_len + 1),
+        ),
+    ]
+    .into_iter()
+    .flat_map(|(label, ra)| sample_executables(ENTRYPOINT, ra, Some(label.to_string()), true))
+    .collect()
+}
+
 fn to_clvalue_labeled<T>(value: T) -> (String, CLValue)
 where
     T: CLTyped + ToBytes + Debug,
@@ -0,0 +1,46 @@
+//! Sample test vectors for deploys that are well-formed Casper transactions
+//! but can't be represented on Ledger, so the device should reject them.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs, U512};
+
+use crate::sample::Sample;
+
+pub(super) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let delegator: PublicKey = PublicKey::ed25519_from_bytes([1u8; 32]).unwrap();
+    let validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let amount = U512::from(100000000u32);
+
+    // System key does not represent any account.
+    let system_delegator = ExecutableDeployItem::StoredContractByName {
+        name: "delegate_contract".to_string(),
+        entry_point: "delegate".to_string(),
+        args: runtime_args! {
+            "delegator" => PublicKey::system(),
+            "validator" => validator.clone(),
+            "amount" => amount,
+        },
+    };
+
+    // Amount is not a number.
+    let amount_not_number = ExecutableDeployItem::StoredContractByName {
+        name: "delegate_contract".to_string(),
+        entry_point: "delegate".to_string(),
+        args: runtime_args! {
+            "delegator" => delegator,
+            "validator" => validator,
+            "amount" => "one hundred",
+        },
+    };
+
+    vec![
+        Sample::new("system_public_key", system_delegator, false),
+        Sample::new("amount_not_number", amount_not_number, false),
+    ]
+    .into_iter()
+    .map(|sample| {
+        let (label, item, valid) = sample.destructure();
+        Sample::new(format!("malformed__{}", label), item, valid)
+    })
+    .collect()
+}
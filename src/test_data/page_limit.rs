@@ -0,0 +1,100 @@
+//! Sample test vectors for transactions that sit right at the page limit.
+//!
+//! Calls of a stored contract by name are used as a base since their regular mode representation
+//! grows with the length of the contract's `name`, one page at a time, regardless of the device
+//! and the arguments' mode (the call has no arguments). For each of the target page counts
+//! (one below, exactly at and one above the limit) the shortest name that yields it is picked.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::RuntimeArgs;
+
+use crate::sample::Sample;
+
+use super::{make_deploy_sample, random_keys, system_payment, DEFAULT_CHAIN_NAME, MIN_TTL};
+
+// Longest contract name tried. Enough for 255 pages on any of the supported devices.
+const MAX_NAME_LEN: usize = 65_536;
+
+fn contract_call_with_name_len(name_len: usize) -> Sample<Deploy> {
+    let session = Sample::new(
+        "stored_contract_by_name",
+        ExecutableDeployItem::StoredContractByName {
+            name: "c".repeat(name_len),
+            entry_point: "call".to_string(),
+            args: RuntimeArgs::new(),
+        },
+        true,
+    );
+    make_deploy_sample(
+        session,
+        system_payment::valid(),
+        MIN_TTL,
+        vec![],
+        DEFAULT_CHAIN_NAME,
+        &random_keys(1),
+    )
+}
+
+// Returns the shortest name length for which the call spans at least `target` pages.
+// Page count never decreases as the name grows, so it can be binary searched.
+fn min_name_len<F>(target: usize, page_count: &F) -> usize
+where
+    F: Fn(&Deploy) -> usize,
+{
+    let pages = |name_len| {
+        let (_, deploy, _) = contract_call_with_name_len(name_len).destructure();
+        page_count(&deploy)
+    };
+    let (mut low, mut high) = (1, MAX_NAME_LEN);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pages(mid) < target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Returns valid samples whose regular mode representation spans
+/// `page_limit - 1`, `page_limit` and `page_limit + 1` pages.
+///
+/// `page_count` returns number of pages the deploy spans in the regular mode.
+///
+/// # Panics
+///
+/// Panics when any of the page counts can't be reached, i.e. when it's lower than
+/// the page count of the shortest call.
+pub(super) fn valid<F>(page_limit: u8, page_count: F) -> Vec<Sample<Deploy>>
+where
+    F: Fn(&Deploy) -> usize,
+{
+    let page_limit = page_limit as usize;
+    let targets = vec![
+        ("below", page_limit.saturating_sub(1)),
+        ("at", page_limit),
+        ("above", page_limit + 1),
+    ];
+
+    targets
+        .into_iter()
+        .map(|(label, target)| {
+            let sample = contract_call_with_name_len(min_name_len(target, &page_count));
+            let (sample_label, deploy, valid) = sample.destructure();
+            let pages = page_count(&deploy);
+            if pages != target {
+                panic!(
+                    "can't build a page_limit_{} sample spanning {} pages, the closest one spans {}",
+                    label, target, pages
+                );
+            }
+            Sample::new(
+                format!("page_limit_{}__{}", label, sample_label),
+                deploy,
+                valid,
+            )
+        })
+        .collect()
+}
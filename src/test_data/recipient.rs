@@ -0,0 +1,75 @@
+//! Sample test vectors for native transfers to the same recipient, encoded in different ways.
+//!
+//! The mint accepts the target account as its public key, account hash bytes or `Key::Account`.
+//! Each of them is displayed differently, but they all share the same account hash line.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{AsymmetricType, Key, PublicKey, U512};
+
+use crate::sample::Sample;
+
+use super::{
+    make_deploy_sample, random_keys, system_payment, NativeTransfer, TransferSource,
+    TransferTarget, DEFAULT_CHAIN_NAME, MIN_TTL,
+};
+
+fn sample_recipients() -> Vec<(&'static str, PublicKey)> {
+    vec![
+        ("ed25519", PublicKey::ed25519_from_bytes([7u8; 32]).unwrap()),
+        (
+            "secp256k1",
+            PublicKey::secp256k1_from_bytes(
+                hex::decode(b"026e1b7a8e3243f5ff14e825b0fde15103588bb61e6ae99084968b017118e0504f")
+                    .unwrap(),
+            )
+            .unwrap(),
+        ),
+    ]
+}
+
+fn encodings(recipient: &PublicKey) -> Vec<(&'static str, TransferTarget)> {
+    let account_hash = recipient.to_account_hash();
+    vec![
+        ("public_key", TransferTarget::PublicKey(recipient.clone())),
+        ("bytes", TransferTarget::Bytes(account_hash.value())),
+        (
+            "key_account",
+            TransferTarget::Key(Key::Account(account_hash)),
+        ),
+    ]
+}
+
+/// Returns native transfers to each of the recipients, in each of the target encodings.
+pub(super) fn valid() -> Vec<Sample<Deploy>> {
+    let keys = random_keys(1);
+    sample_recipients()
+        .into_iter()
+        .flat_map(|(recipient_label, recipient)| {
+            encodings(&recipient)
+                .into_iter()
+                .map(move |(encoding_label, target)| {
+                    (format!("{}__{}", recipient_label, encoding_label), target)
+                })
+        })
+        .map(|(label, target)| {
+            let transfer =
+                NativeTransfer::new(target, U512::from(100000000u64), 0, TransferSource::none());
+            let session = Sample::new(
+                format!("native_transfer_recipient_{}", label),
+                ExecutableDeployItem::Transfer {
+                    args: transfer.into(),
+                },
+                true,
+            );
+            make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MIN_TTL,
+                vec![],
+                DEFAULT_CHAIN_NAME,
+                &keys,
+            )
+        })
+        .collect()
+}
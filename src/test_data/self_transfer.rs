@@ -0,0 +1,75 @@
+//! Sample test vectors for native transfers to the sender itself and to purses.
+//!
+//! The sender's account can be the target in each of the encodings the mint accepts:
+//! its public key, its account hash as raw bytes and as `Key::Account`.
+//! Purses are `URef`s (or `Key::URef`s) with write rights.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{AccessRights, Key, PublicKey, URef, U512};
+
+use crate::sample::Sample;
+
+use super::{
+    commons::UREF_ADDR, make_deploy_sample, random_keys, system_payment, NativeTransfer,
+    TransferSource, TransferTarget, DEFAULT_CHAIN_NAME, MIN_TTL,
+};
+
+fn sample_targets(account: &PublicKey) -> Vec<(&'static str, TransferTarget)> {
+    let account_hash = account.to_account_hash();
+    vec![
+        (
+            "self_public_key",
+            TransferTarget::PublicKey(account.clone()),
+        ),
+        ("self_bytes", TransferTarget::Bytes(account_hash.value())),
+        (
+            "self_key_account",
+            TransferTarget::Key(Key::Account(account_hash)),
+        ),
+        (
+            "purse_uref",
+            TransferTarget::URef(URef::new(UREF_ADDR, AccessRights::WRITE)),
+        ),
+        (
+            "purse_key_uref",
+            TransferTarget::Key(Key::URef(URef::new(
+                UREF_ADDR,
+                AccessRights::READ_ADD_WRITE,
+            ))),
+        ),
+        // Without write rights it's not a purse tokens can be transferred to.
+        (
+            "read_only_uref",
+            TransferTarget::URef(URef::new(UREF_ADDR, AccessRights::READ)),
+        ),
+    ]
+}
+
+/// Returns native transfers to the account that signs them, and to purses.
+pub(super) fn valid() -> Vec<Sample<Deploy>> {
+    let keys = random_keys(1);
+    let account = PublicKey::from(&keys[0]);
+    sample_targets(&account)
+        .into_iter()
+        .map(|(label, target)| {
+            let transfer =
+                NativeTransfer::new(target, U512::from(100000000u64), 0, TransferSource::none());
+            let session = Sample::new(
+                format!("native_transfer_{}", label),
+                ExecutableDeployItem::Transfer {
+                    args: transfer.into(),
+                },
+                true,
+            );
+            make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MIN_TTL,
+                vec![],
+                DEFAULT_CHAIN_NAME,
+                &keys,
+            )
+        })
+        .collect()
+}
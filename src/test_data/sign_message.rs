@@ -1,17 +1,44 @@
-use crate::{message::CasperMessage, sample::Sample};
+use crate::{
+    message::{CasperMessage, MAX_MESSAGE_LENGTH},
+    sample::Sample,
+};
 
 const SAMPLE_MESSAGE: &str = "Please sign this CSPR token donation";
 
-/// Returns sample with valid CasperMessage for signing.
+/// Returns samples with valid CasperMessage for signing.
+///
+/// Besides the plain text message, covers the ones that span many pages, have multiple lines
+/// or non-ASCII characters, and the ones that can't be displayed as text.
 pub(crate) fn valid_casper_message_sample() -> Vec<Sample<CasperMessage>> {
-    vec![Sample::new(
-        "valid_casper_message",
-        CasperMessage::new(SAMPLE_MESSAGE.as_bytes().to_vec()),
-        true,
-    )]
+    let messages: Vec<(&str, Vec<u8>)> = vec![
+        ("valid_casper_message", SAMPLE_MESSAGE.as_bytes().to_vec()),
+        (
+            "valid_casper_message_long",
+            SAMPLE_MESSAGE.repeat(10).into_bytes(),
+        ),
+        (
+            "valid_casper_message_multiline",
+            b"Please sign this CSPR token donation\nAmount: 10 CSPR\nNonce: 1".to_vec(),
+        ),
+        (
+            "valid_casper_message_unicode",
+            "Zażółć gęślą jaźń – 签名 ✓".as_bytes().to_vec(),
+        ),
+        (
+            "valid_casper_message_control_characters",
+            b"Please sign\tthis\x07 CSPR token donation\r".to_vec(),
+        ),
+    ];
+
+    messages
+        .into_iter()
+        .map(|(label, msg)| Sample::new(label, CasperMessage::new(msg), true))
+        .collect()
 }
 
-/// Returns invalid sample of CasperMessage for signing.
+/// Returns invalid samples of CasperMessage for signing.
+///
+/// Every sample breaks one of the rules of `CasperMessage::parse`, named in its label.
 pub(crate) fn invalid_casper_message_sample() -> Vec<Sample<CasperMessage>> {
     let invalid_header = vec![
         "Casper:",
@@ -25,12 +52,35 @@ pub(crate) fn invalid_casper_message_sample() -> Vec<Sample<CasperMessage>> {
 
     let msg = SAMPLE_MESSAGE.as_bytes();
 
-    invalid_header
+    let mut messages: Vec<CasperMessage> = invalid_header
         .map(|prefix| {
             let mut output: Vec<u8> = prefix;
-            output.extend(msg.clone());
-            let message = CasperMessage::raw(output);
-            Sample::new("invalid_casper_message", message, false)
+            output.extend(msg);
+            CasperMessage::raw(output)
         })
-        .collect()
+        .collect();
+
+    messages.extend(vec![
+        // Too long.
+        CasperMessage::new(vec![b'a'; MAX_MESSAGE_LENGTH]),
+        // Empty.
+        CasperMessage::new(vec![]),
+        // Invalid UTF-8.
+        CasperMessage::new(vec![0x00, 0x01, 0x02, 0xfe, 0xff]),
+    ]);
+
+    messages.into_iter().map(invalid_sample).collect()
+}
+
+// Labels the `message` with the rule it breaks.
+fn invalid_sample(message: CasperMessage) -> Sample<CasperMessage> {
+    let error = match CasperMessage::parse(message.inner().to_vec()) {
+        Ok(_) => panic!("invalid message sample should break one of the rules"),
+        Err(error) => error,
+    };
+    Sample::new(
+        format!("invalid_casper_message_{}", error.name()),
+        message,
+        false,
+    )
 }
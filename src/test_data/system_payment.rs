@@ -3,6 +3,8 @@ use casper_types::{bytesrepr::Bytes, runtime_args, RuntimeArgs, U512};
 
 use crate::sample::Sample;
 
+use super::{commons::sample_executables, wasm::call_module};
+
 pub(super) fn valid() -> Sample<ExecutableDeployItem> {
     let payment = ExecutableDeployItem::ModuleBytes {
         module_bytes: Bytes::new(),
@@ -24,3 +26,37 @@ pub(super) fn invalid() -> Sample<ExecutableDeployItem> {
 
     Sample::new("payment_system_missing_amount", payment, false)
 }
+
+/// Returns payments made by stored contracts and payment Wasm instead of the system payment.
+pub(super) fn custom() -> Vec<Sample<ExecutableDeployItem>> {
+    let args = runtime_args! {
+        "amount" => U512::from(1000000000)
+    };
+
+    let mut samples = sample_executables("pay", args.clone(), None, true);
+    samples.push(Sample::new(
+        "type_module_bytes",
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::from(call_module()),
+            args,
+        },
+        true,
+    ));
+    samples.push(Sample::new(
+        "type_by_name_no_amount",
+        ExecutableDeployItem::StoredContractByName {
+            name: "pay_contract".to_string(),
+            entry_point: "pay".to_string(),
+            args: RuntimeArgs::new(),
+        },
+        true,
+    ));
+
+    samples
+        .into_iter()
+        .map(|sample| {
+            let (label, item, valid) = sample.destructure();
+            Sample::new(format!("payment_custom_{}", label), item, valid)
+        })
+        .collect()
+}
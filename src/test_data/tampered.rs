@@ -0,0 +1,82 @@
+//! Sample test vectors for deploys whose hashes or approvals were tampered with.
+//!
+//! They are well-formed and can be represented on Ledger but would be rejected by a node,
+//! so the device must reject them as well.
+//!
+//! Each of them is built out of a valid native transfer by replacing one of its parts
+//! with the one taken from another, slightly different, deploy.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{TimeDiff, U512};
+
+use crate::sample::Sample;
+
+use super::{
+    make_deploy_sample, random_keys, system_payment, NativeTransfer, TransferSource,
+    TransferTarget, DEFAULT_CHAIN_NAME, MIN_TTL, TTL_HOUR,
+};
+
+fn native_transfer(amount: u64, ttl: TimeDiff) -> Deploy {
+    let transfer = NativeTransfer::new(
+        TransferTarget::bytes(),
+        U512::from(amount),
+        0,
+        TransferSource::none(),
+    );
+    let session = Sample::new(
+        "native_transfer",
+        ExecutableDeployItem::Transfer {
+            args: transfer.into(),
+        },
+        true,
+    );
+    let (_label, deploy, _valid) = make_deploy_sample(
+        session,
+        system_payment::valid(),
+        ttl,
+        vec![],
+        DEFAULT_CHAIN_NAME,
+        &random_keys(2),
+    )
+    .destructure();
+    deploy
+}
+
+// Returns `deploy` with its JSON `field` replaced with the one from `other`.
+fn replace_field(deploy: &Deploy, other: &Deploy, field: &str) -> Deploy {
+    let mut json = serde_json::to_value(deploy).expect("deploy should serialize to JSON");
+    let other_json = serde_json::to_value(other).expect("deploy should serialize to JSON");
+    json[field] = other_json[field].clone();
+    serde_json::from_value(json).expect("deploy should deserialize from JSON")
+}
+
+pub(super) fn invalid() -> Vec<Sample<Deploy>> {
+    let deploy = native_transfer(1_000_000_000, MIN_TTL);
+    // Different body, same header fields.
+    let other_body = native_transfer(2_000_000_000, MIN_TTL);
+    // Same body, different header fields.
+    let other_header = native_transfer(1_000_000_000, TTL_HOUR);
+
+    // Body hash in the header doesn't match the payment and session code.
+    let tampered_body = replace_field(&deploy, &other_body, "session");
+    // Deploy hash doesn't match the header.
+    let tampered_header = replace_field(&deploy, &other_header, "header");
+    // Approvals are made by the right keys, but for another deploy.
+    let forged_approvals = replace_field(&deploy, &other_body, "approvals");
+
+    vec![
+        ("body_hash_mismatch", tampered_body),
+        ("header_hash_mismatch", tampered_header),
+        ("forged_approval", forged_approvals),
+    ]
+    .into_iter()
+    .map(|(label, deploy)| {
+        Sample::new(
+            format!("tampered_{}__native_transfer__payment_system", label),
+            deploy,
+            false,
+        )
+    })
+    .collect()
+}
@@ -0,0 +1,118 @@
+//! Sample test vectors for deploys with custom Wasm session code.
+//!
+//! Modules are handcrafted, valid Wasm binaries. Besides the smallest possible ones,
+//! there are modules padded with a custom section so that the deploy doesn't fit
+//! into the Ledger app's transaction buffer and has to be sent in many APDU chunks.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{bytesrepr::Bytes, runtime_args, RuntimeArgs, U512};
+
+use crate::sample::Sample;
+
+// Size of the transaction buffer assumed for the Ledger app.
+const TX_BUFFER_SIZE: usize = 10 * 1024;
+
+// `\0asm` magic number followed by the version 1.
+const WASM_HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+// Module with a single `call` function exported, that does nothing.
+const CALL_SECTIONS: [u8; 26] = [
+    // Type section: a single `() -> ()` function type.
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, //
+    // Function section: a single function of type 0.
+    0x03, 0x02, 0x01, 0x00, //
+    // Export section: function 0 exported as `call`.
+    0x07, 0x08, 0x01, 0x04, b'c', b'a', b'l', b'l', 0x00, 0x00, //
+    // Code section: a single function with no locals and an empty body.
+    0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b,
+];
+
+// Encodes `value` as unsigned LEB128, as used by the Wasm binary format.
+fn leb128(mut value: usize) -> Vec<u8> {
+    let mut output = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            output.push(byte);
+            return output;
+        }
+        output.push(byte | 0x80);
+    }
+}
+
+fn empty_module() -> Vec<u8> {
+    WASM_HEADER.to_vec()
+}
+
+pub(super) fn call_module() -> Vec<u8> {
+    let mut module = WASM_HEADER.to_vec();
+    module.extend(CALL_SECTIONS);
+    module
+}
+
+// Returns the `call` module with a custom section of `padding` zero bytes appended.
+fn padded_module(padding: usize) -> Vec<u8> {
+    const SECTION_NAME: &[u8] = b"padding";
+    let mut section = leb128(SECTION_NAME.len());
+    section.extend(SECTION_NAME);
+    section.extend(vec![0u8; padding]);
+
+    let mut module = call_module();
+    module.push(0x00);
+    module.extend(leb128(section.len()));
+    module.extend(section);
+    module
+}
+
+fn session_wasm(module: Vec<u8>, args: RuntimeArgs) -> ExecutableDeployItem {
+    ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::from(module),
+        args,
+    }
+}
+
+pub(super) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let args = runtime_args! {
+        "amount" => U512::from(1_000_000_000u64),
+    };
+
+    vec![
+        ("empty_module", empty_module(), RuntimeArgs::new()),
+        ("call_module", call_module(), RuntimeArgs::new()),
+        ("call_module_with_args", call_module(), args.clone()),
+        (
+            "below_tx_buffer",
+            padded_module(TX_BUFFER_SIZE / 2),
+            args.clone(),
+        ),
+        (
+            "above_tx_buffer",
+            padded_module(TX_BUFFER_SIZE),
+            args.clone(),
+        ),
+        ("large", padded_module(TX_BUFFER_SIZE * 10), args),
+    ]
+    .into_iter()
+    .map(|(label, module, args)| {
+        Sample::new(
+            format!("session_wasm_{}", label),
+            session_wasm(module, args),
+            true,
+        )
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::leb128;
+
+    #[test]
+    fn leb128_encoding() {
+        assert_eq!(vec![0x00], leb128(0));
+        assert_eq!(vec![0x7f], leb128(127));
+        assert_eq!(vec![0x80, 0x01], leb128(128));
+        assert_eq!(vec![0xe5, 0x8e, 0x26], leb128(624_485));
+    }
+}
@@ -0,0 +1,85 @@
+//! Cryptographic verification of the `Deploy`.
+//!
+//! Mirrors the checks a node does before accepting a deploy: the body hash has to match
+//! the payment and session code, the deploy hash has to match the header and every approval
+//! has to be a valid signature of the deploy hash.
+//!
+//! These are the checks of `Deploy::is_valid`, made here so that the test vector can tell which
+//! of them failed. They can't drift apart from the node's: they only involve the hashes and
+//! signatures stored in the deploy itself, so they are part of the protocol - a node checking them
+//! differently would reject deploys that are already on the chain. The serialization, hashing and
+//! signature verification are not re-implemented, they come from the casper-node dependency.
+
+use std::fmt::{self, Display, Formatter};
+
+use casper_hashing::Digest;
+use casper_node::types::Deploy;
+use casper_types::{bytesrepr::ToBytes, crypto, PublicKey};
+
+/// Reasons why the `Deploy` would be rejected by a node.
+#[derive(Debug)]
+pub(crate) enum VerificationError {
+    /// Part of the deploy could not be serialized, so its hash can't be computed.
+    ToBytes,
+    /// Header's body hash is not the hash of the payment and session code.
+    BodyHashMismatch,
+    /// Deploy hash is not the hash of the header.
+    DeployHashMismatch,
+    /// Deploy has no approvals.
+    NoApprovals,
+    /// Approval's signature is not a valid signature of the deploy hash.
+    InvalidApproval { signer: PublicKey },
+}
+
+impl Display for VerificationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::ToBytes => write!(f, "can't serialize deploy"),
+            VerificationError::BodyHashMismatch => write!(f, "body hash mismatch"),
+            VerificationError::DeployHashMismatch => write!(f, "deploy hash mismatch"),
+            VerificationError::NoApprovals => write!(f, "no approvals"),
+            VerificationError::InvalidApproval { signer } => {
+                write!(f, "invalid approval by {}", signer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+/// Verifies the hashes and approvals of the `Deploy`.
+pub(crate) fn verify_deploy(deploy: &Deploy) -> Result<(), VerificationError> {
+    let mut body = deploy
+        .payment()
+        .to_bytes()
+        .map_err(|_| VerificationError::ToBytes)?;
+    body.extend(
+        deploy
+            .session()
+            .to_bytes()
+            .map_err(|_| VerificationError::ToBytes)?,
+    );
+    if Digest::hash(body) != *deploy.header().body_hash() {
+        return Err(VerificationError::BodyHashMismatch);
+    }
+
+    let header = deploy
+        .header()
+        .to_bytes()
+        .map_err(|_| VerificationError::ToBytes)?;
+    if Digest::hash(header) != *deploy.hash().inner() {
+        return Err(VerificationError::DeployHashMismatch);
+    }
+
+    if deploy.approvals().is_empty() {
+        return Err(VerificationError::NoApprovals);
+    }
+    for approval in deploy.approvals() {
+        crypto::verify(deploy.hash(), approval.signature(), approval.signer()).map_err(|_| {
+            VerificationError::InvalidApproval {
+                signer: approval.signer().clone(),
+            }
+        })?;
+    }
+    Ok(())
+}